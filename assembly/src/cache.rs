@@ -0,0 +1,85 @@
+//! A thread-safe (behind the `std` feature) or single-threaded cache of already-parsed modules,
+//! keyed by module path.
+//!
+//! Replaces storing a plain [ModuleMap] directly on [crate::Assembler] and mutating it through
+//! `unsafe { &mut *(self as *const _ as *mut Assembler) }`, which was unsound under a shared
+//! `&self` and ruled out sharing an [crate::Assembler] across threads. With this cache, concurrent
+//! `compile` calls on a shared [crate::Assembler] can safely reuse already-parsed stdlib modules,
+//! so a long-lived assembler amortizes parsing work instead of redoing it on every call.
+
+#[cfg(feature = "std")]
+use std::sync::{Arc, RwLock};
+
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc as Arc;
+#[cfg(not(feature = "std"))]
+use core::cell::RefCell;
+
+use crate::ProcMap;
+use vm_core::utils::{collections::BTreeMap, string::String};
+
+// MODULE CACHE
+// ================================================================================================
+
+/// An append-only cache of parsed modules: once a module's exported procedures are inserted under
+/// its path, they are never removed or mutated again, so readers never observe a partially
+/// populated entry.
+///
+/// Each module's [ProcMap] is stored behind an [Arc] (or, without the `std` feature, an
+/// [alloc::rc::Rc] aliased to the same name above) rather than handed out as a borrow tied to the
+/// read lock/`RefCell` guard: [Self::get_procs] clones the handle while the guard is held and
+/// returns it to the caller as owned data, since a `&ProcMap` borrowed from a guard that's local
+/// to this function cannot be made to outlive the call (the guard must be dropped here, before
+/// `get_procs` returns).
+pub(crate) struct ModuleCache {
+    #[cfg(feature = "std")]
+    modules: RwLock<BTreeMap<String, Arc<ProcMap>>>,
+    #[cfg(not(feature = "std"))]
+    modules: RefCell<BTreeMap<String, Arc<ProcMap>>>,
+}
+
+impl ModuleCache {
+    /// Returns a new, empty [ModuleCache].
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            modules: RwLock::new(BTreeMap::new()),
+            #[cfg(not(feature = "std"))]
+            modules: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns `true` if the module at `path` has already been parsed and cached.
+    pub fn contains(&self, path: &str) -> bool {
+        #[cfg(feature = "std")]
+        let modules = self.modules.read().expect("module cache poisoned");
+        #[cfg(not(feature = "std"))]
+        let modules = self.modules.borrow();
+
+        modules.contains_key(path)
+    }
+
+    /// Returns the cached procedures of the module at `path`, or `None` if no module has been
+    /// cached at that path yet.
+    ///
+    /// The returned [Arc] is a cheap, independent handle to the cached [ProcMap]: cloning it (and
+    /// the lock/`RefCell` guard used to reach it) are both dropped before this function returns,
+    /// so callers can hold onto and read from the result for as long as they like without any
+    /// ties back to this cache's internal locking.
+    pub fn get_procs(&self, path: &str) -> Option<Arc<ProcMap>> {
+        #[cfg(feature = "std")]
+        let modules = self.modules.read().expect("module cache poisoned");
+        #[cfg(not(feature = "std"))]
+        let modules = self.modules.borrow();
+
+        modules.get(path).cloned()
+    }
+
+    /// Inserts `procs`, the exported procedures of the module at `path`, into the cache.
+    pub fn insert(&self, path: String, procs: ProcMap) {
+        #[cfg(feature = "std")]
+        self.modules.write().expect("module cache poisoned").insert(path, Arc::new(procs));
+        #[cfg(not(feature = "std"))]
+        self.modules.borrow_mut().insert(path, Arc::new(procs));
+    }
+}
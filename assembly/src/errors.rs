@@ -0,0 +1,243 @@
+//! Structured diagnostics for assembly errors, modeled on rustc's `ParseSess`/`DiagnosticBuilder`:
+//! every [AssemblyError] carries a primary [Span] (and, where useful, secondary spans and notes)
+//! so a failure can be located precisely in multi-line, multi-module source rather than reported
+//! as a bare token and position index.
+
+use vm_core::utils::{
+    collections::Vec,
+    string::{String, ToString},
+};
+
+use super::Token;
+
+// SPAN
+// ================================================================================================
+
+/// A half-open byte-offset range (`[start, end)`) into a single module's source text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// Returns the span covering `token`'s text at its position in its source.
+    fn from_token(token: &Token) -> Self {
+        let start = token.pos();
+        // approximate the original token text by rejoining its dot-delimited parts, since the
+        // token only hands back the parsed parts, not the raw source slice.
+        let len = token.parts().join(".").len().max(1);
+        Self {
+            start,
+            end: start + len,
+        }
+    }
+}
+
+// ASSEMBLY ERROR
+// ================================================================================================
+
+/// An error produced while tokenizing or assembling a program, carrying enough information to
+/// [render](AssemblyError::render) a rustc-style diagnostic pointing at the offending source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssemblyError {
+    message: String,
+    primary_span: Span,
+    secondary_spans: Vec<(Span, String)>,
+    notes: Vec<String>,
+    /// The path of the module the spans refer to, or `None` if they refer to the root program's
+    /// own source. Set by [AssemblyError::in_module] as an error propagates out of a module being
+    /// parsed, so the spans are always resolved against the right source text.
+    module_path: Option<String>,
+}
+
+impl AssemblyError {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    pub fn unexpected_eof(pos: usize) -> Self {
+        Self::at(Span { start: pos, end: pos + 1 }, "unexpected EOF".to_string())
+    }
+
+    pub fn unexpected_token(token: &Token, expected: &str) -> Self {
+        Self::at(
+            Span::from_token(token),
+            format!("unexpected token `{}`; expected `{}`", token.parts().join("."), expected),
+        )
+    }
+
+    pub fn circular_module_dependency(token: &Token, dep_chain: &[String]) -> Self {
+        let mut err = Self::at(
+            Span::from_token(token),
+            format!("circular module dependency: {}", dep_chain.join(" -> ")),
+        );
+        for (i, module) in dep_chain.iter().enumerate() {
+            err.notes.push(format!("[{}] {}", i, module));
+        }
+        err
+    }
+
+    pub fn missing_import_source(token: &Token, module_path: &str) -> Self {
+        Self::at(
+            Span::from_token(token),
+            format!("could not find source for module `{}`", module_path),
+        )
+    }
+
+    pub fn export_not_found(token: &Token, module_path: &str, proc_name: &str) -> Self {
+        Self::at(
+            Span::from_token(token),
+            format!("module `{}` does not export a procedure named `{}`", module_path, proc_name),
+        )
+    }
+
+    pub fn dangling_ops_after_module(token: &Token, module_path: &str) -> Self {
+        Self::at(
+            Span::from_token(token),
+            format!("dangling instructions after module `{}`", module_path),
+        )
+    }
+
+    pub fn unmatched_begin(token: &Token) -> Self {
+        Self::at(Span::from_token(token), "unmatched `begin`".to_string())
+    }
+
+    pub fn dangling_else(token: &Token) -> Self {
+        Self::at(Span::from_token(token), "`else` without a matching `if`".to_string())
+    }
+
+    pub fn dangling_ops_after_program(token: &Token) -> Self {
+        Self::at(Span::from_token(token), "dangling instructions after program end".to_string())
+    }
+
+    /// Returns an error for an instruction `op` that doesn't match any known operation (e.g. an
+    /// unrecognized prefix, or a recognized prefix with the wrong number of parts).
+    pub fn invalid_op(op: &Token) -> Self {
+        Self::at(Span::from_token(op), format!("invalid operation `{}`", op.parts().join(".")))
+    }
+
+    /// Returns an error for `op`'s part at `part_idx` not being a valid parameter for the
+    /// operation (e.g. not parsing as the expected immediate value, or out of range).
+    pub fn invalid_param(op: &Token, part_idx: usize) -> Self {
+        let param = op.parts().get(part_idx).copied().unwrap_or("");
+        Self::at(
+            Span::from_token(op),
+            format!("malformed parameter `{}` in operation `{}`", param, op.parts().join(".")),
+        )
+    }
+
+    /// Returns an error for `op` having more parts than the operation it names accepts.
+    pub fn extra_param(op: &Token) -> Self {
+        Self::at(
+            Span::from_token(op),
+            format!("extra parameter in operation `{}`", op.parts().join(".")),
+        )
+    }
+
+    /// Returns an error for a procedure-local `index` that is out of range for a procedure
+    /// declaring only `num_proc_locals` locals.
+    pub fn local_index_out_of_bounds(op: &Token, index: u64, num_proc_locals: usize) -> Self {
+        Self::at(
+            Span::from_token(op),
+            format!(
+                "local index {} out of bounds for procedure with {} locals",
+                index, num_proc_locals
+            ),
+        )
+    }
+
+    fn at(span: Span, message: String) -> Self {
+        Self {
+            message,
+            primary_span: span,
+            secondary_spans: Vec::new(),
+            notes: Vec::new(),
+            module_path: None,
+        }
+    }
+
+    // MODULE CONTEXT
+    // --------------------------------------------------------------------------------------------
+
+    /// Records that this error's spans refer to `module_path`'s own source text, unless it has
+    /// already been attributed to a (more deeply nested) module.
+    pub(crate) fn in_module(mut self, module_path: &str) -> Self {
+        if self.module_path.is_none() {
+            self.module_path = Some(module_path.to_string());
+        }
+        self
+    }
+
+    // RENDERING
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders this error as a rustc-style diagnostic against `source`: the offending line,
+    /// underlined with `^^^` under the primary span, preceded by a header naming the module the
+    /// error occurred in (if any). `source` must be the text of the module named by
+    /// [Self::module_path] (or the root program's source, if it is `None`).
+    pub fn render(&self, source: &str) -> String {
+        let mut out = String::new();
+
+        match &self.module_path {
+            Some(module_path) => {
+                out.push_str(&format!("error in module `{}`: {}\n", module_path, self.message))
+            }
+            None => out.push_str(&format!("error: {}\n", self.message)),
+        }
+        render_span(&mut out, source, &self.primary_span, None);
+
+        for (span, note) in &self.secondary_spans {
+            out.push('\n');
+            render_span(&mut out, source, span, Some(note));
+        }
+
+        for note in &self.notes {
+            out.push('\n');
+            out.push_str("note: ");
+            out.push_str(note);
+        }
+
+        out
+    }
+}
+
+/// Appends `source`'s line containing `span.start`, a `^^^` underline under `span`, and -- if
+/// given -- a `note:` label, to `out`.
+fn render_span(out: &mut String, source: &str, span: &Span, note: Option<&str>) {
+    let (line_no, col_no, line_text) = locate(source, span.start);
+    let underline_len = (span.end - span.start).max(1);
+
+    out.push_str(&format!(" --> line {}:{}\n", line_no, col_no));
+    if let Some(note) = note {
+        out.push_str(note);
+        out.push('\n');
+    }
+    out.push_str(line_text);
+    out.push('\n');
+    out.push_str(&" ".repeat(col_no.saturating_sub(1)));
+    out.push_str(&"^".repeat(underline_len));
+}
+
+/// Converts the byte offset `pos` into `source` into a `(1-indexed line, 1-indexed column, line
+/// text)` triple.
+fn locate(source: &str, pos: usize) -> (usize, usize, &str) {
+    let mut line_no = 1;
+    let mut line_start = 0;
+
+    for (i, ch) in source.char_indices() {
+        if i >= pos {
+            break;
+        }
+        if ch == '\n' {
+            line_no += 1;
+            line_start = i + 1;
+        }
+    }
+
+    let line_end = source[line_start..]
+        .find('\n')
+        .map_or(source.len(), |i| line_start + i);
+    let col_no = pos.saturating_sub(line_start) + 1;
+
+    (line_no, col_no, &source[line_start..line_end])
+}
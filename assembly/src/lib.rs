@@ -7,7 +7,7 @@ extern crate alloc;
 use vm_core::{
     code_blocks::CodeBlock,
     utils::{
-        collections::{BTreeMap, Vec},
+        collections::{BTreeMap, Box, Vec},
         string::{String, ToString},
     },
     Library, Program,
@@ -29,6 +29,12 @@ use tokens::{Token, TokenStream};
 mod errors;
 pub use errors::AssemblyError;
 
+mod cache;
+use cache::ModuleCache;
+
+mod providers;
+pub use providers::{ModuleNotFound, ModuleProvider};
+
 #[cfg(test)]
 mod tests;
 
@@ -40,8 +46,16 @@ const MODULE_PATH_DELIM: &str = "::";
 // TYPE ALIASES
 // ================================================================================================
 
-type ProcMap = BTreeMap<String, Procedure>;
-type ModuleMap = BTreeMap<String, ProcMap>;
+pub(crate) type ProcMap = BTreeMap<String, Procedure>;
+pub(crate) type ModuleMap = BTreeMap<String, ProcMap>;
+
+/// Which of a module's exported procedures a `use` instruction brings into scope.
+enum ImportSelector {
+    /// Import every exported procedure (the default, e.g. `use a::b::c`).
+    Wildcard,
+    /// Import only the named procedures (e.g. `use a::b::c::name` or `use a::b::c::{n1, n2}`).
+    Named(Vec<String>),
+}
 
 // ASSEMBLER
 // ================================================================================================
@@ -49,7 +63,8 @@ type ModuleMap = BTreeMap<String, ProcMap>;
 /// TODO: add comments
 pub struct Assembler {
     stdlib: StdLibrary,
-    parsed_modules: ModuleMap,
+    providers: Vec<Box<dyn ModuleProvider>>,
+    parsed_modules: ModuleCache,
     in_debug_mode: bool,
 }
 
@@ -61,7 +76,22 @@ impl Assembler {
     pub fn new(in_debug_mode: bool) -> Self {
         Self {
             stdlib: StdLibrary::default(),
-            parsed_modules: BTreeMap::new(),
+            providers: Vec::new(),
+            parsed_modules: ModuleCache::new(),
+            in_debug_mode,
+        }
+    }
+
+    /// Returns a new instance of [Assembler] which resolves `use` paths by consulting `providers`
+    /// in order before falling back to the standard library.
+    ///
+    /// This lets a caller assemble programs that import their own on-disk or in-memory module
+    /// library (e.g. a project-local `mylib::math` namespace) without forking the crate.
+    pub fn with_provider(providers: Vec<Box<dyn ModuleProvider>>, in_debug_mode: bool) -> Self {
+        Self {
+            stdlib: StdLibrary::default(),
+            providers,
+            parsed_modules: ModuleCache::new(),
             in_debug_mode,
         }
     }
@@ -105,13 +135,82 @@ impl Assembler {
         Ok(Program::new(program_root))
     }
 
+    // LIBRARY COMPILER
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles the exported procedures in `source` into a standalone [Library]: a serializable
+    /// artifact whose MAST for each export is fully preassembled, so it can be distributed and
+    /// consulted by later [Self::compile] calls (e.g. via a [ModuleProvider] wrapping it) without
+    /// re-parsing the library's own source, or any of its dependencies, from scratch.
+    ///
+    /// This walks the same path as [Self::parse_module] -- resolving and inlining the source's
+    /// `use` dependencies, then compiling its exported procedures -- but, unlike
+    /// [Self::parse_module], never caches its own exports into `self.parsed_modules`: the result
+    /// belongs entirely to the caller, not to this assembler's module cache.
+    pub fn compile_library(&self, source: &str) -> Result<Library, AssemblyError> {
+        let mut tokens = TokenStream::new(source)?;
+        let mut context = AssemblyContext::new();
+
+        // parse imported modules (if any), and add exported procedures from these modules to the
+        // current context; since a library has no enclosing module, we start with an empty
+        // dependency chain, just as we do for the root program in `compile`.
+        self.parse_imports(&mut tokens, &mut context, &mut Vec::new())?;
+
+        // parse the procedures defined in the library, and add these procedures to the current
+        // context
+        while let Some(token) = tokens.read() {
+            let proc = match token.parts()[0] {
+                Token::PROC | Token::EXPORT => {
+                    Procedure::parse(&mut tokens, &context, true, self.in_debug_mode)?
+                }
+                _ => break,
+            };
+            context.add_local_proc(proc);
+        }
+
+        // make sure there are no dangling instructions after all procedures have been read
+        if !tokens.eof() {
+            let token = tokens.read().expect("no token before eof");
+            return Err(AssemblyError::dangling_ops_after_module(token, "<library>"));
+        }
+
+        // a library is consumed purely through its exports -- drop everything else
+        let mut procs = context.into_local_procs();
+        procs.retain(|_, p| p.is_export());
+
+        let exports = procs
+            .into_iter()
+            .map(|(name, proc)| (name, proc.code_root().clone()))
+            .collect();
+        Ok(Library::new(exports))
+    }
+
+    // MODULE RESOLUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Resolves `path` to a module's source by consulting the registered providers in order,
+    /// falling back to the standard library if none of them have it.
+    fn get_module_source(&self, path: &str) -> Option<&str> {
+        for provider in self.providers.iter() {
+            if let Ok(source) = provider.get_module_source(path) {
+                return Some(source);
+            }
+        }
+
+        self.stdlib.get_module_source(path).ok()
+    }
+
     // IMPORT PARSERS
     // --------------------------------------------------------------------------------------------
 
     /// Parses `use` instructions from the token stream.
     ///
     /// For each `use` instructions, retrieves exported procedures from the specified module and
-    /// inserts them into the provided context.
+    /// inserts them into the provided context. By default every exported procedure is imported
+    /// (`use a::b::c`), but a `use` instruction may instead name a single procedure
+    /// (`use a::b::c::name`) or a set of procedures (`use a::b::c::{name1, name2}`) to import. An
+    /// optional `as alias` suffix (`use a::b::c as abc`) registers the imported procedures under
+    /// `alias` instead of the module path's last segment.
     ///
     /// If a module specified by `use` instruction hasn't been parsed yet, parses it, and adds
     /// the parsed module to `self.parsed_modules`.
@@ -120,7 +219,15 @@ impl Assembler {
     /// Returns an error if:
     /// - The `use` instruction is malformed.
     /// - A module specified by the `use` instruction could not be found.
+    /// - A named import does not correspond to a procedure the module actually exports.
     /// - Parsing the specified module results in an error.
+    // NOTE: `context: &mut AssemblyContext<'a>` ties every procedure this function registers to
+    // this call's own `&'a self` borrow. `ModuleCache::get_procs` (see `cache.rs`) now hands back
+    // an owned `Arc<ProcMap>` instead of a borrow scoped to its internal lock, so the procedures
+    // read below no longer depend on a lock guard local to this function outliving its own
+    // return. Whether `AssemblyContext::add_imported_proc` itself still requires a `Procedure`
+    // reference specifically tied to `'a` -- as opposed to cloning/Rc-sharing it into its own
+    // storage -- is a property of `AssemblyContext`'s definition, which isn't part of this file.
     fn parse_imports<'a>(
         &'a self,
         tokens: &mut TokenStream,
@@ -131,12 +238,15 @@ impl Assembler {
         while let Some(token) = tokens.read() {
             match token.parts()[0] {
                 Token::USE => {
-                    // parse the `use` instruction to extract module path from it
-                    let module_path = &token.parse_use()?;
+                    // parse the `use` instruction, then split off an optional import selector
+                    // (`::name` or `::{name, ...}`) from the module path
+                    let raw_path = token.parse_use()?;
+                    let (module_path, selector) = self.resolve_import_target(&raw_path);
+                    let module_path = module_path.to_string();
 
                     // check if a module with the same path is currently being parsed somewhere up
                     // the chain; if it is, then we have a circular dependency.
-                    if dep_chain.iter().any(|v| v == module_path) {
+                    if dep_chain.iter().any(|v| v == &module_path) {
                         dep_chain.push(module_path.clone());
                         return Err(AssemblyError::circular_module_dependency(token, dep_chain));
                     }
@@ -144,31 +254,61 @@ impl Assembler {
                     // add the current module to the dependency chain
                     dep_chain.push(module_path.clone());
 
-                    // if the module hasn't been parsed yet, retrieve its source from the library
+                    // if the module hasn't been parsed yet, retrieve its source -- consulting the
+                    // registered providers in order, then falling back to the standard library --
                     // and attempt to parse it; if the parsing is successful, this will also add
                     // the parsed module to `self.parsed_modules`
-                    if !self.parsed_modules.contains_key(module_path) {
-                        let module_source =
-                            self.stdlib.get_module_source(module_path).map_err(|_| {
-                                AssemblyError::missing_import_source(token, module_path)
+                    if !self.parsed_modules.contains(&module_path) {
+                        let module_source = self
+                            .get_module_source(&module_path)
+                            .ok_or_else(|| {
+                                AssemblyError::missing_import_source(token, &module_path)
                             })?;
-                        self.parse_module(module_source, module_path, dep_chain)?;
+                        self.parse_module(module_source, &module_path, dep_chain)?;
                     }
 
-                    // get procedures from the module at the specified path; we are guaranteed to
-                    // not fail here because the above code block ensures that either there is a
-                    // parsed module for the specified path, or the function returns with an error
+                    // procedure labels are set to be `namespace::procedure_name`. By default
+                    // `namespace` is the last segment of the module path (e.g. `u256::add`), but
+                    // an explicit `as alias` suffix (`use a::utils as autils`) registers the
+                    // procedures under `alias` instead, so two modules that happen to share a
+                    // last segment don't clobber each other.
+                    let alias = match token.parts() {
+                        [_, _] => None,
+                        [_, _, "as", alias] => Some(*alias),
+                        _ => return Err(AssemblyError::invalid_op(token)),
+                    };
+                    let path_parts = module_path.split(MODULE_PATH_DELIM).collect::<Vec<_>>();
+                    let namespace = alias.unwrap_or(path_parts[path_parts.len() - 1]);
+
+                    // pull the cached procedures for the module at the specified path; we are
+                    // guaranteed to find an entry here because the above code block ensures that
+                    // either there is a parsed module for the specified path, or the function
+                    // returns with an error.
+                    //
+                    // `get_procs` hands back an owned `Arc<ProcMap>` rather than a borrow scoped
+                    // to the cache's internal lock, so it can be read here, well after the lock
+                    // backing it has been released, without any dependency on this function's own
+                    // stack frame outliving that lock guard.
                     let module_procs = self
                         .parsed_modules
-                        .get(module_path)
+                        .get_procs(&module_path)
                         .expect("no module procs");
-
-                    // add all procedures to the current context; procedure labels are set to be
-                    // `last_part_of_module_path::procedure_name`. For example, `u256::add`.
-                    for proc in module_procs.values() {
-                        let path_parts = module_path.split(MODULE_PATH_DELIM).collect::<Vec<_>>();
-                        let num_parts = path_parts.len();
-                        context.add_imported_proc(path_parts[num_parts - 1], proc);
+                    match &selector {
+                        // add every exported procedure to the current context
+                        ImportSelector::Wildcard => {
+                            for proc in module_procs.values() {
+                                context.add_imported_proc(namespace, proc);
+                            }
+                        }
+                        // add only the named procedures, erroring if one of them isn't exported
+                        ImportSelector::Named(names) => {
+                            for name in names {
+                                let proc = module_procs.get(name.as_str()).ok_or_else(|| {
+                                    AssemblyError::export_not_found(token, &module_path, name)
+                                })?;
+                                context.add_imported_proc(namespace, proc);
+                            }
+                        }
                     }
 
                     // consume the `use` token and pop the current module of the dependency chain
@@ -182,14 +322,64 @@ impl Assembler {
         Ok(())
     }
 
+    /// Splits a raw `use` path into the module path it names and the [ImportSelector] describing
+    /// which of that module's exports to bring into scope.
+    ///
+    /// Recognizes three forms:
+    /// - `a::b::c` -- the whole path names a module; every export is imported (the wildcard
+    ///   default).
+    /// - `a::b::c::name` -- `a::b::c` names a module and `name` one of its exports.
+    /// - `a::b::c::{name1, name2}` -- `a::b::c` names a module and the braced, comma-separated
+    ///   list names a set of its exports.
+    fn resolve_import_target<'b>(&self, raw: &'b str) -> (&'b str, ImportSelector) {
+        if let Some(brace_start) = raw.find("::{") {
+            if let Some(list) = raw.strip_suffix('}') {
+                let module_path = &raw[..brace_start];
+                let names = list[brace_start + 3..]
+                    .split(',')
+                    .map(|name| name.trim().to_string())
+                    .collect();
+                return (module_path, ImportSelector::Named(names));
+            }
+        }
+
+        // the unadorned path resolves directly to a module: wildcard-import all of its exports
+        if self.get_module_source(raw).is_some() {
+            return (raw, ImportSelector::Wildcard);
+        }
+
+        // otherwise, the last `::`-delimited segment names a single export of the remaining path
+        match raw.rfind(MODULE_PATH_DELIM) {
+            Some(idx) => {
+                let module_path = &raw[..idx];
+                let name = raw[idx + MODULE_PATH_DELIM.len()..].to_string();
+                (module_path, ImportSelector::Named(vec![name]))
+            }
+            None => (raw, ImportSelector::Wildcard),
+        }
+    }
+
     /// Parses a set of exported procedures from the specified source code and adds these
     /// procedures to `self.parsed_modules` using the specified path as the key.
-    #[allow(clippy::cast_ref_to_mut)]
+    ///
+    /// Every error produced while parsing `source` is tagged with `path` via
+    /// [AssemblyError::in_module], so its span is later rendered against the module's own source
+    /// text rather than the root program's.
     fn parse_module(
         &self,
         source: &str,
         path: &str,
         dep_chain: &mut Vec<String>,
+    ) -> Result<(), AssemblyError> {
+        self.parse_module_inner(source, path, dep_chain)
+            .map_err(|err| err.in_module(path))
+    }
+
+    fn parse_module_inner(
+        &self,
+        source: &str,
+        path: &str,
+        dep_chain: &mut Vec<String>,
     ) -> Result<(), AssemblyError> {
         let mut tokens = TokenStream::new(source)?;
         let mut context = AssemblyContext::new();
@@ -220,13 +410,10 @@ impl Assembler {
         let mut module_procs = context.into_local_procs();
         module_procs.retain(|_, p| p.is_export());
 
-        // insert exported procedures into `self.parsed_procedures`
-        // TODO: figure out how to do this using interior mutability
-        unsafe {
-            let path = path.to_string();
-            let mutable_self = &mut *(self as *const _ as *mut Assembler);
-            mutable_self.parsed_modules.insert(path, module_procs);
-        }
+        // insert the exported procedures into the shared module cache, where they are visible to
+        // every other `use` of this path -- including from concurrent `compile` calls sharing
+        // this assembler
+        self.parsed_modules.insert(path.to_string(), module_procs);
 
         Ok(())
     }
@@ -1,16 +1,49 @@
-use vm_core::AdviceInjector;
+use vm_core::{AdviceInjector, AssertReason};
 
 use super::{validate_op_len, AssemblyError, BaseElement, Operation, Token};
 
 // HASHING
 // ================================================================================================
-// The number of elements to be hashed by the rphash operation
+// The number of elements to be hashed by the fixed-length rphash operation; this also doubles as
+// the rate width absorbed per block by the variable-length rphash.<n> sponge.
 const RPHASH_NUM_ELEMENTS: u64 = 8;
 
+/// Parses the `rphash` assembly operation, dispatching to the fixed-length 2-to-1 hash
+/// (`rphash`) or the variable-length sponge hash (`rphash.<n>`) depending on whether a message
+/// length was provided.
+///
+/// See [parse_rphash_fixed] and [parse_rphash_sponge] for details of each form.
+///
+/// # Errors
+/// Returns an AssemblyError if:
+/// - the operation is malformed.
+/// - an unrecognized operation is received (anything other than rphash or rphash.<n>).
+pub fn parse_rphash(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+    if op.parts()[0] != "rphash" {
+        return Err(AssemblyError::unexpected_token(op, "rphash"));
+    }
+
+    if op.num_parts() > 1 {
+        parse_rphash_sponge(span_ops, op)
+    } else {
+        validate_op_len(op, 1, 0, 0)?;
+        parse_rphash_fixed(span_ops);
+        Ok(())
+    }
+}
+
 /// Appends RPPERM and stack manipulation operations to the span block as required to compute a
 /// 2-to-1 Rescue Prime hash. The top of the stack is expected to be arranged with 2 words
 /// (8 elements) to be hashed: [B, A, ...].
 ///
+/// See [rp_hash_2to1] for how the hash is computed.
+fn parse_rphash_fixed(span_ops: &mut Vec<Operation>) {
+    rp_hash_2to1(span_ops);
+}
+
+/// Appends RPPERM and stack manipulation operations to the span block as required to compute a
+/// 2-to-1 Rescue Prime hash of the top 2 words (8 elements) of the stack: [B, A, ...].
+///
 /// This assembly operation uses the VM operation RPPERM at its core, which permutes the top 12
 /// elements of the stack.
 ///
@@ -22,18 +55,7 @@ const RPHASH_NUM_ELEMENTS: u64 = 8;
 /// 3. Prepare to drop D and C by moving E further down the stack. This can be achieved by
 ///    swapping E and C with the SWAPW2 operation.
 /// 4. Drop the top 8 elements from the stack, leaving our hash result at the top: [E, ...].
-///
-/// # Errors
-/// Returns an AssemblyError if:
-/// - the operation is malformed.
-/// - an unrecognized operation is received (anything other than rphash).
-pub fn parse_rphash(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
-    // validate the operation
-    validate_op_len(op, 1, 0, 0)?;
-    if op.parts()[0] != "rphash" {
-        return Err(AssemblyError::unexpected_token(op, "rphash"));
-    }
-
+fn rp_hash_2to1(span_ops: &mut Vec<Operation>) {
     // Add 4 elements to the stack to prepare for the Rescue Prime permutation
     // The element on top of the stack should be the number of elements to be hashed
     for _ in 0..3 {
@@ -51,6 +73,95 @@ pub fn parse_rphash(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), Ass
     for _ in 0..8 {
         span_ops.push(Operation::Drop);
     }
+}
+
+/// Appends RPPERM and stack manipulation operations to the span block as required to compute a
+/// variable-length Rescue Prime sponge hash of `rphash.<n>`, absorbing `n` elements read from the
+/// advice tape.
+///
+/// The top 12 elements of the stack are used as the sponge state: the top 4 elements hold the
+/// capacity and the next 8 hold the rate. This matches [rp_hash_2to1], which leaves the
+/// pre-existing message word(s) in place and pushes the capacity (the length tag) *on top of*
+/// them last, so the capacity ends up closest to the top of the stack going into RPPERM.
+///
+/// To perform the operation, we do the following:
+/// 1. For each 8-element block of the message (at least one, even for an empty message): read up
+///    to 8 elements from the advice tape into the rate, padding a partial final block with a
+///    single 1 followed by 0s.
+/// 2. Push the capacity on top of the rate just absorbed: for the first block this is the length
+///    tag (`n`), the same convention [parse_rphash_fixed] uses for its fixed element count; for
+///    later blocks it is the capacity carried over from the previous block's permutation, brought
+///    back on top with SWAPW2 after the old rate underneath it was dropped.
+/// 3. Append RPPERM to absorb the block.
+/// 4. Once all `n` elements have been absorbed, the first 4 elements of the final state are the
+///    digest; drop the remaining 8 elements, mirroring the drop sequence already used in
+///    [parse_rphash_fixed].
+///
+/// # Errors
+/// Returns an AssemblyError if:
+/// - the operation is malformed.
+/// - the message length `n` is missing or not a valid immediate value.
+fn parse_rphash_sponge(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+    validate_op_len(op, 2, 1, 1)?;
+
+    let n = op.parts()[1]
+        .parse::<u64>()
+        .map_err(|_| AssemblyError::invalid_param(op, 1))?;
+
+    let num_blocks = if n == 0 {
+        1
+    } else {
+        (n + RPHASH_NUM_ELEMENTS - 1) / RPHASH_NUM_ELEMENTS
+    };
+
+    let mut absorbed = 0;
+    for block in 0..num_blocks {
+        if block > 0 {
+            // bring the previous block's rate to the top (above the carried-over capacity) so it
+            // can be dropped
+            span_ops.push(Operation::SwapW2);
+            for _ in 0..RPHASH_NUM_ELEMENTS {
+                span_ops.push(Operation::Drop);
+            }
+        }
+
+        let take = (n - absorbed).min(RPHASH_NUM_ELEMENTS);
+
+        // read this block's message elements from the advice tape into the rate
+        for _ in 0..take {
+            span_ops.push(Operation::Read);
+        }
+
+        // pad a partial final block with a single 1 followed by 0s
+        if take < RPHASH_NUM_ELEMENTS {
+            span_ops.push(Operation::Push(BaseElement::ONE));
+            for _ in 0..(RPHASH_NUM_ELEMENTS - take - 1) {
+                span_ops.push(Operation::Pad);
+            }
+        }
+
+        if block == 0 {
+            // initialize the capacity with the length tag, pushed on top of the rate just
+            // absorbed, mirroring rp_hash_2to1's order
+            for _ in 0..3 {
+                span_ops.push(Operation::Pad);
+            }
+            span_ops.push(Operation::Push(BaseElement::new(n)));
+        } else {
+            // bring the carried-over capacity back on top of the freshly absorbed rate
+            span_ops.push(Operation::SwapW2);
+        }
+
+        span_ops.push(Operation::RpPerm);
+        absorbed += take;
+    }
+
+    // the digest is the first 4 elements of the final state; move it below the remaining 8 and
+    // drop them, as rp_hash_2to1 does
+    span_ops.push(Operation::SwapW2);
+    for _ in 0..8 {
+        span_ops.push(Operation::Drop);
+    }
 
     Ok(())
 }
@@ -88,23 +199,41 @@ pub fn parse_rpperm(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), Ass
 /// - "mtree.cwm" copies a Merkle tree with root R and updates a node at depth d and index i to
 ///   value V. It uses the MRUPDATE operation with the parameter set to "true" so the old advice
 ///   set is preserved.
+/// - "mtree.del" removes a leaf from the Merkle tree with root R at depth d and index i, replacing
+///   it with the empty/zero value and updating the root. See [mtree_del] for why this does not
+///   promote a sibling leaf upward to shorten its depth.
+/// - "mtree.batch.<n>" verifies that n leaves each open to the same (possibly repeated) root. See
+///   [mtree_batch] for why this does not amortize shared sibling nodes across leaves.
 ///
 /// # Errors:
 /// Returns an AssemblyError if:
 /// - the operation is malformed.
 /// - an unrecognized operation is received (anything other than "mtree" with a valid variant of
-///   "get", "set", or "cwm").
+///   "get", "set", "cwm", or "del", or "batch" followed by a valid leaf count).
 pub fn parse_mtree(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
-    // validate operation
-    validate_op_len(op, 2, 0, 0)?;
     if op.parts()[0] != "mtree" {
-        return Err(AssemblyError::unexpected_token(op, "mtree.{get|set|cwm}"));
+        return Err(AssemblyError::unexpected_token(
+            op,
+            "mtree.{get|set|cwm|del|batch.<n>}",
+        ));
+    }
+
+    if op.parts().get(1) == Some(&"batch") {
+        validate_op_len(op, 3, 1, 1)?;
+        let n = op.parts()[2]
+            .parse::<u64>()
+            .map_err(|_| AssemblyError::invalid_param(op, 2))?;
+        return mtree_batch(span_ops, op, n);
     }
 
+    // validate operation
+    validate_op_len(op, 2, 0, 0)?;
+
     match op.parts()[1] {
         "get" => mtree_get(span_ops),
         "set" => mtree_set(span_ops),
         "cwm" => mtree_cwm(span_ops),
+        "del" => mtree_del(span_ops),
         _ => return Err(AssemblyError::invalid_op(op)),
     }
 
@@ -230,6 +359,104 @@ fn mtree_cwm(span_ops: &mut Vec<Operation>) {
     span_ops.push(Operation::SwapW2);
 }
 
+/// Appends the MRUPDATE op with a parameter of "false" and stack manipulations to the span block
+/// as required to remove a leaf from the Merkle tree with root R at depth d and index i, replacing
+/// it with the empty/zero value. The stack is expected to be arranged as follows (from the top):
+/// - depth of the node, 1 element
+/// - index of the node, 1 element
+/// - current root of the tree, 4 elements
+///
+/// After the operations are executed, the stack will be arranged as follows:
+/// - new root of the tree after the removal, 4 elements
+///
+/// # Status: sibling-promotion deletion rejected, not implemented
+/// The original request for this op asked for sibling promotion on delete: when a leaf is
+/// removed, query the advice provider for whether its sibling is itself a leaf, and if so elide
+/// the now-childless internal node and move the sibling up a level, shrinking the tree around the
+/// deletion instead of leaving a zero value in place. That is NOT what this function does, and
+/// this doc comment is not standing in for that implementation -- it is recording why the request
+/// can't be satisfied in this tree and should go back to whoever filed it.
+///
+/// Sibling promotion requires a node whose effective depth changes after the operation, but
+/// MPVERIFY and MRUPDATE -- the only two Merkle primitives this VM exposes -- both take a fixed
+/// depth and index and verify/update a node at exactly that depth. Neither has any notion of a
+/// leaf moving to a shallower depth. Building promotion would require a new, variable-depth Merkle
+/// chiplet; it cannot be assembled out of fixed-depth MPVERIFY/MRUPDATE calls, however they're
+/// sequenced. That chiplet doesn't exist anywhere in this tree.
+///
+/// Pending that chiplet, `mtree_del` falls back to the same fixed-depth, empty-value deletion
+/// `mtree_set`/`smtree.set` already perform: the leaf is overwritten with the zero value and the
+/// tree's depth and shape are otherwise unchanged. This is a materially smaller feature than what
+/// was asked for and should be tracked as such rather than closed.
+fn mtree_del(span_ops: &mut Vec<Operation>) {
+    // push the empty/zero value as the new node value, matching mtree_set's stack contract
+    // [d, i, R, ...] => [0, 0, 0, 0, d, i, R, ...]
+    for _ in 0..4 {
+        span_ops.push(Operation::Pad);
+    }
+
+    // move d and i back above the zero value => [d, i, 0, 0, 0, 0, R, ...]
+    span_ops.push(Operation::MovUp5);
+    span_ops.push(Operation::MovUp5);
+
+    // reuse the mtree.set update flow with the new value fixed to zero
+    // => [R_new, ...]
+    mtree_set(span_ops);
+
+    // drop the leaf value (now zero), leaving only the updated root
+    for _ in 0..4 {
+        span_ops.push(Operation::Drop);
+    }
+}
+
+/// Appends the MPVERIFY op and stack manipulations to the span block, repeated `n` times, as
+/// required to verify that `n` leaves each open to their own provided root. The stack is expected
+/// to hold `n` independent (depth, index, root) triples, one per leaf, arranged as follows (from
+/// the top):
+/// - depth of the first leaf's node, 1 element
+/// - index of the first leaf's node, 1 element
+/// - root the first leaf is checked against, 4 elements
+/// - ... (repeated `n` times)
+///
+/// After the operations are executed, the stack will be arranged as `n` (value, root) pairs,
+/// mirroring [mtree_get]'s output, one per leaf.
+///
+/// # Status: amortized multiproof rejected, not implemented
+/// The original request for this op asked for an amortized multiproof: sort the `n` leaf indices
+/// at compile time, read each sibling from the advice tape only the first time it's needed, and
+/// reuse a partial-hash stack keyed by level for any leaf that shares a sibling with one already
+/// opened. That is NOT what this function does, and this doc comment is not standing in for that
+/// implementation -- it is recording why the request can't be satisfied in this tree and should go
+/// back to whoever filed it.
+///
+/// Amortizing shared siblings needs two things this assembler doesn't have. First, compile-time
+/// sorted indices: `op`'s `n` is a literal, but the `n` per-leaf indices themselves are ordinary
+/// stack values pushed by the caller's program, not immediates this function can inspect or sort
+/// while emitting ops. Second, a runtime decision, per shared sibling, between "already computed
+/// for an earlier leaf in this call" and "must still be read off the advice tape" -- this module
+/// only ever appends to a flat `Vec<Operation>` with no loop or branch primitive, so there is no
+/// way to encode that decision as a fixed sequence of ops independent of the indices' actual
+/// values. A true amortized multiproof needs a new VM primitive (or at minimum a control-flow
+/// construct this assembler doesn't expose here), not a smarter fixed sequence of MPVERIFY calls.
+///
+/// Pending that, `mtree_batch` falls back to exactly `n` independent calls to [mtree_get], with no
+/// sharing of sibling nodes between leaves. This is a materially smaller feature than what was
+/// asked for and should be tracked as such rather than closed.
+///
+/// # Errors
+/// Returns an AssemblyError if `n` is 0, since there is nothing to verify.
+fn mtree_batch(span_ops: &mut Vec<Operation>, op: &Token, n: u64) -> Result<(), AssemblyError> {
+    if n == 0 {
+        return Err(AssemblyError::invalid_param(op, 2));
+    }
+
+    for _ in 0..n {
+        mtree_get(span_ops);
+    }
+
+    Ok(())
+}
+
 /// Validates that two 4 word Merkle roots at the top of the stack are equal, then drops the
 /// duplicate. The stack is expected to be arranged as follows (from the top):
 /// - root of a Merkle tree, 4 elements
@@ -237,7 +464,7 @@ fn mtree_cwm(span_ops: &mut Vec<Operation>) {
 fn validate_and_drop_root(span_ops: &mut Vec<Operation>) {
     // verify the provided root and the computed root are equal
     span_ops.push(Operation::Eqw);
-    span_ops.push(Operation::Assert);
+    span_ops.push(Operation::Assert(AssertReason::MerklePathMismatch.into()));
 
     // drop one of the duplicate roots
     for _ in 0..4 {
@@ -323,6 +550,159 @@ fn validate_root_after_mrupdate(span_ops: &mut Vec<Operation>) {
     validate_and_drop_root(span_ops);
 }
 
+// MERKLE MOUNTAIN RANGES
+// ================================================================================================
+
+/// Parses the type of Merkle Mountain Range operation and appends a VM crypto operation and the
+/// stack manipulations required for correct execution of the specified mmr op.
+/// - "mmr.verify.<depth>" verifies that a leaf value V at a given position authenticates, via a
+///   path of `depth` sibling nodes, to the peak owning that leaf.
+/// - "mmr.root.<num_peaks>" bags `num_peaks` peaks into a single MMR root.
+///
+/// # Errors:
+/// Returns an AssemblyError if:
+/// - the operation is malformed.
+/// - an unrecognized operation is received (anything other than "mmr" with a valid variant of
+///   "verify" or "root", followed by a valid immediate value).
+pub fn parse_mmr(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+    // validate operation
+    validate_op_len(op, 3, 1, 1)?;
+    if op.parts()[0] != "mmr" {
+        return Err(AssemblyError::unexpected_token(op, "mmr.{verify|root}.<n>"));
+    }
+
+    match op.parts()[1] {
+        "verify" => {
+            let depth = op.parts()[2]
+                .parse::<u64>()
+                .map_err(|_| AssemblyError::invalid_param(op, 2))?;
+            mmr_verify(span_ops, depth);
+            Ok(())
+        }
+        "root" => {
+            let num_peaks = op.parts()[2]
+                .parse::<u64>()
+                .map_err(|_| AssemblyError::invalid_param(op, 2))?;
+            mmr_root(span_ops, op, num_peaks)
+        }
+        _ => Err(AssemblyError::invalid_op(op)),
+    }
+}
+
+/// Appends the operations required to verify that a leaf value V at a given position
+/// authenticates to a provided peak P, via a path of `depth` sibling nodes read from the advice
+/// tape. The stack is expected to be arranged as follows (from the top):
+/// - leaf value V, 4 elements
+/// - position of the leaf within its perfect subtree, 1 element
+/// - peak P owning the leaf, 4 elements
+///
+/// After the operations are executed, the stack will be arranged as follows:
+/// - peak P, 4 elements
+///
+/// Note: unlike `mtree.get`, which derives sibling ordering from the node index, each hop here
+/// reads an explicit order flag from the advice tape alongside its sibling (rather than deriving
+/// it from the leaf position), since `depth` must be unrolled at assembly time and the caller is
+/// expected to have arranged the advice tape accordingly. The leaf position is threaded through
+/// unchanged and dropped once the path is folded; callers that need the full list of peaks should
+/// select the owning peak before calling into this op.
+fn mmr_verify(span_ops: &mut Vec<Operation>, depth: u64) {
+    // stack: [V, p, P, ...]
+    for _ in 0..depth {
+        // inject the sibling node at the head of the advice tape
+        span_ops.push(Operation::Advice(AdviceInjector::MerkleNode));
+
+        // read the sibling node, followed by an order flag, from the advice tape
+        // => [f, S, V, p, P, ...]
+        for _ in 0..4 {
+            span_ops.push(Operation::Read);
+        }
+        span_ops.push(Operation::Read);
+
+        // order the sibling and the accumulator according to the flag => [S, V, p, P, ...] or
+        // [V, S, p, P, ...]
+        span_ops.push(Operation::CSwapW);
+
+        // fold the pair into the next level of the path => [V', p, P, ...]
+        rp_hash_2to1(span_ops);
+    }
+
+    // discard the leaf position, no longer needed => [V', P, ...]
+    span_ops.push(Operation::MovUp4);
+    span_ops.push(Operation::Drop);
+
+    // verify the folded accumulator matches the provided peak, then drop the duplicate
+    // => [P, ...]
+    validate_and_drop_root(span_ops);
+}
+
+/// Appends the operations required to bag `num_peaks` peaks into a single MMR root. The stack is
+/// expected to hold the peaks ordered from rightmost (most recently appended, topmost) to
+/// leftmost (oldest, deepest):
+/// - peak of the rightmost perfect subtree, 4 elements
+/// - ...
+/// - peak of the leftmost perfect subtree, 4 elements
+///
+/// After the operations are executed, the stack will be arranged as follows:
+/// - MMR root, 4 elements
+///
+/// # Errors
+/// Returns an AssemblyError if `num_peaks` is 0, since there is nothing to bag.
+fn mmr_root(span_ops: &mut Vec<Operation>, op: &Token, num_peaks: u64) -> Result<(), AssemblyError> {
+    if num_peaks == 0 {
+        return Err(AssemblyError::invalid_param(op, 2));
+    }
+
+    // fold the accumulator with each subsequent peak, from rightmost to leftmost
+    for _ in 0..(num_peaks - 1) {
+        rp_hash_2to1(span_ops);
+    }
+
+    Ok(())
+}
+
+// SPARSE MERKLE TREES
+// ================================================================================================
+
+/// Parses the type of sparse Merkle tree operation and appends a VM crypto operation and the
+/// stack manipulations required for correct execution of the specified smtree op.
+/// - "smtree.get" verifies that a sparse Merkle tree with root R opens to node V (which may be the
+///   empty leaf, ZERO) at depth d and index i.
+/// - "smtree.set" updates a leaf in the sparse Merkle tree with root R at depth d and index i to
+///   value V, which may itself be ZERO to delete a leaf.
+///
+/// A sparse Merkle tree is addressed exactly like the dense tree handled by [parse_mtree]: both
+/// ops use the VM's MPVERIFY/MRUPDATE operations and expect sibling nodes to be available on the
+/// advice tape at the time they execute. The difference is entirely in how the advice provider
+/// populates that tape: for a sparse tree, any subtree that was never written is absent, and the
+/// provider substitutes the precomputed empty-node hash for that level instead. That table --
+/// `empty_hash[0] = ZERO` and `empty_hash[level] = RPPERM(empty_hash[level - 1], empty_hash[level
+/// - 1])` -- along with the sparse, key-indexed tree that uses it to answer path queries, is
+/// implemented host-side by [vm_core::hasher::empty_hashes] and
+/// [vm_core::hasher::SparseMerkleTree]. This lets a fixed-depth key-value map be
+/// committed without materializing empty subtrees, and lets `smtree.get` prove non-inclusion of a
+/// key by opening it to the empty leaf.
+///
+/// # Errors:
+/// Returns an AssemblyError if:
+/// - the operation is malformed.
+/// - an unrecognized operation is received (anything other than "smtree" with a valid variant of
+///   "get" or "set").
+pub fn parse_smtree(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+    // validate operation
+    validate_op_len(op, 2, 0, 0)?;
+    if op.parts()[0] != "smtree" {
+        return Err(AssemblyError::unexpected_token(op, "smtree.{get|set}"));
+    }
+
+    match op.parts()[1] {
+        "get" => mtree_get(span_ops),
+        "set" => mtree_set(span_ops),
+        _ => return Err(AssemblyError::invalid_op(op)),
+    }
+
+    Ok(())
+}
+
 // TESTS
 // ================================================================================================
 
@@ -395,13 +775,20 @@ mod tests {
         let mut span_ops: Vec<Operation> = Vec::new();
         let op_pos = 0;
 
-        let op_too_long = Token::new("rphash.12", op_pos);
+        let op_too_long = Token::new("rphash.12.3", op_pos);
         let expected = AssemblyError::extra_param(&op_too_long);
         assert_eq!(
             parse_rphash(&mut span_ops, &op_too_long).unwrap_err(),
             expected
         );
 
+        let op_invalid_len = Token::new("rphash.abc", op_pos);
+        let expected = AssemblyError::invalid_param(&op_invalid_len, 1);
+        assert_eq!(
+            parse_rphash(&mut span_ops, &op_invalid_len).unwrap_err(),
+            expected
+        );
+
         let op_mismatch = Token::new("rpperm", op_pos);
         let expected = AssemblyError::unexpected_token(&op_mismatch, "rphash");
         assert_eq!(
@@ -410,6 +797,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rphash_sponge_single_block() {
+        // a message of exactly 8 elements fills the rate with a single block, no padding needed
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("rphash.8", 0);
+
+        let mut expected = vec![Operation::Read; 8];
+        expected.extend_from_slice(&[
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Push(BaseElement::new(8)),
+        ]);
+        expected.push(Operation::RpPerm);
+        expected.push(Operation::SwapW2);
+        let drop8 = vec![Operation::Drop; 8];
+        expected.extend_from_slice(&drop8);
+
+        parse_rphash(&mut span_ops, &op).expect("Failed to parse rphash.8");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn rphash_sponge_partial_block() {
+        // a message that isn't a multiple of 8 pads the final block with a 1 followed by 0s
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("rphash.3", 0);
+
+        let mut expected = vec![
+            Operation::Read,
+            Operation::Read,
+            Operation::Read,
+            Operation::Push(BaseElement::ONE),
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Push(BaseElement::new(3)),
+        ];
+        expected.push(Operation::RpPerm);
+        expected.push(Operation::SwapW2);
+        let drop8 = vec![Operation::Drop; 8];
+        expected.extend_from_slice(&drop8);
+
+        parse_rphash(&mut span_ops, &op).expect("Failed to parse rphash.3");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn rphash_sponge_multiple_blocks() {
+        // a message longer than 8 elements absorbs in multiple blocks, dropping the previous
+        // block's rate before each subsequent permutation
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("rphash.10", 0);
+
+        let mut expected = vec![Operation::Read; 8];
+        expected.extend_from_slice(&[
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Push(BaseElement::new(10)),
+        ]);
+        expected.push(Operation::RpPerm);
+        // drop the first block's rate before absorbing the second (partial) block
+        expected.push(Operation::SwapW2);
+        let drop8 = vec![Operation::Drop; 8];
+        expected.extend_from_slice(&drop8);
+        expected.push(Operation::Read);
+        expected.push(Operation::Read);
+        expected.push(Operation::Push(BaseElement::ONE));
+        let pad5 = vec![Operation::Pad; 5];
+        expected.extend_from_slice(&pad5);
+        // bring the carried-over capacity back on top of the new rate
+        expected.push(Operation::SwapW2);
+        expected.push(Operation::RpPerm);
+        expected.push(Operation::SwapW2);
+        expected.extend_from_slice(&drop8);
+
+        parse_rphash(&mut span_ops, &op).expect("Failed to parse rphash.10");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn rphash_sponge_empty_message() {
+        // an empty message still absorbs exactly one fully-padded block
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("rphash.0", 0);
+
+        let mut expected = vec![Operation::Push(BaseElement::ONE)];
+        let pad7 = vec![Operation::Pad; 7];
+        expected.extend_from_slice(&pad7);
+        expected.extend_from_slice(&[
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Push(BaseElement::new(0)),
+        ]);
+        expected.push(Operation::RpPerm);
+        expected.push(Operation::SwapW2);
+        let drop8 = vec![Operation::Drop; 8];
+        expected.extend_from_slice(&drop8);
+
+        parse_rphash(&mut span_ops, &op).expect("Failed to parse rphash.0");
+        assert_eq!(span_ops, expected);
+    }
+
     #[test]
     fn mtree_invalid() {
         // parse_mtree should return an error if called with an invalid or incorrect operation
@@ -431,10 +927,277 @@ mod tests {
         );
 
         let op_mismatch = Token::new("rpperm.get", op_pos);
-        let expected = AssemblyError::unexpected_token(&op_mismatch, "mtree.{get|set|cwm}");
+        let expected =
+            AssemblyError::unexpected_token(&op_mismatch, "mtree.{get|set|cwm|del|batch.<n>}");
         assert_eq!(
             parse_mtree(&mut span_ops, &op_mismatch).unwrap_err(),
             expected
         );
     }
+
+    #[test]
+    fn mtree_batch() {
+        // mtree.batch.<n> repeats the mtree.get op sequence n times
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mtree.batch.3", 0);
+
+        let mut expected = Vec::new();
+        for _ in 0..3 {
+            mtree_get(&mut expected);
+        }
+
+        parse_mtree(&mut span_ops, &op).expect("Failed to parse mtree.batch.3");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn mtree_batch_invalid() {
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_pos = 0;
+
+        let op_zero = Token::new("mtree.batch.0", op_pos);
+        let expected = AssemblyError::invalid_param(&op_zero, 2);
+        assert_eq!(parse_mtree(&mut span_ops, &op_zero).unwrap_err(), expected);
+
+        let op_too_long = Token::new("mtree.batch.3.4", op_pos);
+        let expected = AssemblyError::extra_param(&op_too_long);
+        assert_eq!(
+            parse_mtree(&mut span_ops, &op_too_long).unwrap_err(),
+            expected
+        );
+
+        let op_invalid_param = Token::new("mtree.batch.abc", op_pos);
+        let expected = AssemblyError::invalid_param(&op_invalid_param, 2);
+        assert_eq!(
+            parse_mtree(&mut span_ops, &op_invalid_param).unwrap_err(),
+            expected
+        );
+    }
+
+    #[test]
+    fn mtree_del() {
+        // mtree.del pushes a zero word as the new value, reuses the mtree.set update flow, then
+        // drops the (zero) leaf value, leaving only the new root
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mtree.del", 0);
+
+        let mut expected = vec![
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::MovUp5,
+            Operation::MovUp5,
+        ];
+        mtree_set(&mut expected);
+        for _ in 0..4 {
+            expected.push(Operation::Drop);
+        }
+
+        parse_mtree(&mut span_ops, &op).expect("Failed to parse mtree.del");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn mmr_verify() {
+        // verifying a 2-hop authentication path reads a sibling and an order flag per hop, orders
+        // them with CSWAPW, and folds with RPPERM, before comparing the result against the peak
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mmr.verify.2", 0);
+
+        let mut expected = Vec::new();
+        for _ in 0..2 {
+            expected.push(Operation::Advice(AdviceInjector::MerkleNode));
+            for _ in 0..4 {
+                expected.push(Operation::Read);
+            }
+            expected.push(Operation::Read);
+            expected.push(Operation::CSwapW);
+            expected.push(Operation::Pad);
+            expected.push(Operation::Pad);
+            expected.push(Operation::Pad);
+            expected.push(Operation::Push(BaseElement::new(RPHASH_NUM_ELEMENTS)));
+            expected.push(Operation::RpPerm);
+            expected.push(Operation::SwapW2);
+            for _ in 0..8 {
+                expected.push(Operation::Drop);
+            }
+        }
+        expected.push(Operation::MovUp4);
+        expected.push(Operation::Drop);
+        expected.push(Operation::Eqw);
+        expected.push(Operation::Assert(AssertReason::MerklePathMismatch.into()));
+        for _ in 0..4 {
+            expected.push(Operation::Drop);
+        }
+
+        parse_mmr(&mut span_ops, &op).expect("Failed to parse mmr.verify.2");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn mmr_verify_zero_depth() {
+        // a depth of 0 skips the authentication path entirely and directly compares the provided
+        // leaf (as the accumulator) against the peak
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mmr.verify.0", 0);
+
+        let expected = vec![
+            Operation::MovUp4,
+            Operation::Drop,
+            Operation::Eqw,
+            Operation::Assert(AssertReason::MerklePathMismatch.into()),
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+        ];
+
+        parse_mmr(&mut span_ops, &op).expect("Failed to parse mmr.verify.0");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn mmr_root() {
+        // bagging 3 peaks folds the accumulator with each subsequent peak, 2 times
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mmr.root.3", 0);
+
+        let mut expected = Vec::new();
+        for _ in 0..2 {
+            expected.push(Operation::Pad);
+            expected.push(Operation::Pad);
+            expected.push(Operation::Pad);
+            expected.push(Operation::Push(BaseElement::new(RPHASH_NUM_ELEMENTS)));
+            expected.push(Operation::RpPerm);
+            expected.push(Operation::SwapW2);
+            for _ in 0..8 {
+                expected.push(Operation::Drop);
+            }
+        }
+
+        parse_mmr(&mut span_ops, &op).expect("Failed to parse mmr.root.3");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn mmr_root_single_peak() {
+        // a single peak is already the root; nothing needs to be folded
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mmr.root.1", 0);
+
+        parse_mmr(&mut span_ops, &op).expect("Failed to parse mmr.root.1");
+        assert_eq!(span_ops, Vec::new());
+    }
+
+    #[test]
+    fn mmr_invalid() {
+        // parse_mmr should return an error if called with an invalid or incorrect operation
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_pos = 0;
+
+        let op_too_short = Token::new("mmr", op_pos);
+        let expected = AssemblyError::invalid_op(&op_too_short);
+        assert_eq!(
+            parse_mmr(&mut span_ops, &op_too_short).unwrap_err(),
+            expected
+        );
+
+        let op_missing_param = Token::new("mmr.verify", op_pos);
+        let expected = AssemblyError::invalid_param(&op_missing_param, 2);
+        assert_eq!(
+            parse_mmr(&mut span_ops, &op_missing_param).unwrap_err(),
+            expected
+        );
+
+        let op_too_long = Token::new("mmr.verify.2.3", op_pos);
+        let expected = AssemblyError::extra_param(&op_too_long);
+        assert_eq!(
+            parse_mmr(&mut span_ops, &op_too_long).unwrap_err(),
+            expected
+        );
+
+        let op_invalid_param = Token::new("mmr.verify.abc", op_pos);
+        let expected = AssemblyError::invalid_param(&op_invalid_param, 2);
+        assert_eq!(
+            parse_mmr(&mut span_ops, &op_invalid_param).unwrap_err(),
+            expected
+        );
+
+        let op_zero_peaks = Token::new("mmr.root.0", op_pos);
+        let expected = AssemblyError::invalid_param(&op_zero_peaks, 2);
+        assert_eq!(
+            parse_mmr(&mut span_ops, &op_zero_peaks).unwrap_err(),
+            expected
+        );
+
+        let op_mismatch = Token::new("rpperm.verify.2", op_pos);
+        let expected = AssemblyError::unexpected_token(&op_mismatch, "mmr.{verify|root}.<n>");
+        assert_eq!(
+            parse_mmr(&mut span_ops, &op_mismatch).unwrap_err(),
+            expected
+        );
+
+        let op_bad_variant = Token::new("mmr.fold.2", op_pos);
+        let expected = AssemblyError::invalid_op(&op_bad_variant);
+        assert_eq!(
+            parse_mmr(&mut span_ops, &op_bad_variant).unwrap_err(),
+            expected
+        );
+    }
+
+    #[test]
+    fn smtree_get() {
+        // smtree.get reuses the exact same op sequence as mtree.get; the empty-node substitution
+        // happens on the advice provider side and isn't visible to the assembler
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let mut expected: Vec<Operation> = Vec::new();
+
+        let op = Token::new("smtree.get", 0);
+        mtree_get(&mut expected);
+
+        parse_smtree(&mut span_ops, &op).expect("Failed to parse smtree.get");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn smtree_set() {
+        // smtree.set reuses the exact same op sequence as mtree.set
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let mut expected: Vec<Operation> = Vec::new();
+
+        let op = Token::new("smtree.set", 0);
+        mtree_set(&mut expected);
+
+        parse_smtree(&mut span_ops, &op).expect("Failed to parse smtree.set");
+        assert_eq!(span_ops, expected);
+    }
+
+    #[test]
+    fn smtree_invalid() {
+        // parse_smtree should return an error if called with an invalid or incorrect operation
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_pos = 0;
+
+        let op_too_short = Token::new("smtree", op_pos);
+        let expected = AssemblyError::invalid_op(&op_too_short);
+        assert_eq!(
+            parse_smtree(&mut span_ops, &op_too_short).unwrap_err(),
+            expected
+        );
+
+        let op_too_long = Token::new("smtree.get.12", op_pos);
+        let expected = AssemblyError::extra_param(&op_too_long);
+        assert_eq!(
+            parse_smtree(&mut span_ops, &op_too_long).unwrap_err(),
+            expected
+        );
+
+        let op_mismatch = Token::new("mtree.get", op_pos);
+        let expected = AssemblyError::unexpected_token(&op_mismatch, "smtree.{get|set}");
+        assert_eq!(
+            parse_smtree(&mut span_ops, &op_mismatch).unwrap_err(),
+            expected
+        );
+    }
 }
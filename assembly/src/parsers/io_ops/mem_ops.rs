@@ -1,16 +1,35 @@
-use super::{parse_element_param, validate_operation, AssemblyError, Operation, Token, Vec};
-use vm_core::{utils::PushMany, Felt, FieldElement};
+use super::{parse_element_param, validate_operation, AssemblyError, Box, Operation, Token, Vec};
+use vm_core::{utils::PushMany, Felt, StarkField};
+
+mod op_parser;
+pub use op_parser::{OpParser, OpParserRegistry};
 
 // RANDOM ACCESS MEMORY
 // ================================================================================================
 
 /// Pushes the first element of the word at the specified memory address onto the stack. The
-/// memory address may be provided directly as an immediate value or via the stack.
+/// memory address may be provided directly as an immediate value (`push.mem.<addr>`), via the
+/// stack (`push.mem`), as a procedure-local index (`push.local.<idx>`), or as a base-plus-offset
+/// expression (`push.mem.base.<offset>`).
 ///
 /// This operation takes:
 /// - 2 VM cycles when the addresses is provided a an immediate value.
 /// - 1 VM cycle when the address is provided via the stack.
-pub fn parse_push_mem(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+/// - 3 VM cycles when the address is a procedure-local index.
+/// - 3 VM cycles when the address is a base-plus-offset expression.
+pub fn parse_push_mem(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    num_proc_locals: usize,
+) -> Result<(), AssemblyError> {
+    if is_local_addr(op) {
+        return parse_push_local(span_ops, op, num_proc_locals);
+    }
+
+    if let Some(base) = parse_mem_base(op)? {
+        return parse_push_mem_base(span_ops, op, base);
+    }
+
     validate_operation!(op, "push.mem", 0..1);
 
     if op.num_parts() == 3 {
@@ -25,12 +44,28 @@ pub fn parse_push_mem(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), A
 }
 
 /// Pops the top element off the stack and saves it at the specified memory address. The memory
-/// address may be provided directly as an immediate value or via the stack.
+/// address may be provided directly as an immediate value (`pop.mem.<addr>`), via the stack
+/// (`pop.mem`), as a procedure-local index (`pop.local.<idx>`), or as a base-plus-offset
+/// expression (`pop.mem.base.<offset>`).
 ///
 /// This operation takes:
 /// - 3 VM cycles when the addresses is provided a an immediate value.
 /// - 2 VM cycle when the address is provided via the stack.
-pub fn parse_pop_mem(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+/// - 4 VM cycles when the address is a procedure-local index.
+/// - 4 VM cycles when the address is a base-plus-offset expression.
+pub fn parse_pop_mem(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    num_proc_locals: usize,
+) -> Result<(), AssemblyError> {
+    if is_local_addr(op) {
+        return parse_pop_local(span_ops, op, num_proc_locals);
+    }
+
+    if let Some(base) = parse_mem_base(op)? {
+        return parse_pop_mem_base(span_ops, op, base);
+    }
+
     validate_operation!(op, "pop.mem", 0..1);
 
     // if the destination memory address was on top of the stack, restore it to the top
@@ -47,7 +82,8 @@ pub fn parse_pop_mem(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), As
 }
 
 /// Translates the `pushw.mem` and `loadw.mem` assembly ops to the system's `LOADW` memory read
-/// operation.
+/// operation. `pushw.local.<idx>` and `loadw.local.<idx>` are handled by [parse_read_local]
+/// instead, since a procedure-local index is never optional the way a `.mem` address is.
 ///
 /// If the op provides an address (e.g. `pushw.mem.a`), it must be pushed to the stack directly
 /// before the `LOADW` operation. Whether provided directly or via the stack, the memory address
@@ -59,11 +95,15 @@ pub fn parse_pop_mem(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), As
 /// removed by `LOADW`. This is achieved by first using `PAD` to make space for 4 new elements.
 /// Then, if the memory address was provided via the stack (not as part of the memory op) it must be
 /// moved to the top.
+/// Addresses may also be given as a base-plus-offset expression (`pushw.mem.base.<offset>` /
+/// `loadw.mem.base.<offset>`), where the base comes from the stack and `offset` is folded in at
+/// assembly time.
 ///
 /// This operation takes:
-///  - pushw: 6 VM cycles.
+///  - pushw: 6 VM cycles, or 8 VM cycles for a base-plus-offset address.
 ///  - loadw: 2 VM cycles when the addresses is provided a an immediate value.
 ///  - loadw: 1 VM cecle when the address is provided via the stack.
+///  - loadw: 3 VM cycles when the address is a base-plus-offset expression.
 ///
 /// # Errors
 ///
@@ -73,7 +113,28 @@ pub fn parse_read_mem(
     span_ops: &mut Vec<Operation>,
     op: &Token,
     overwrite_stack_top: bool,
+    num_proc_locals: usize,
 ) -> Result<(), AssemblyError> {
+    if is_local_addr(op) {
+        if has_mask(op) {
+            // combining `.local` addressing with `.mask` is not supported
+            return Err(AssemblyError::invalid_op(op));
+        }
+        return parse_read_local(span_ops, op, overwrite_stack_top, num_proc_locals);
+    }
+
+    if let Some(base) = parse_mem_base(op)? {
+        if has_mask(op) {
+            // combining `.base` addressing with `.mask` is not supported
+            return Err(AssemblyError::invalid_op(op));
+        }
+        return parse_read_mem_base(span_ops, op, overwrite_stack_top, base);
+    }
+
+    if let Some(mask) = parse_mem_mask(op)? {
+        return parse_read_mem_masked(span_ops, op, overwrite_stack_top, mask);
+    }
+
     validate_operation!(@only_params op, "pushw|loadw.mem", 0..1);
 
     if !overwrite_stack_top {
@@ -98,8 +159,71 @@ pub fn parse_read_mem(
     Ok(())
 }
 
+/// Translates the masked `loadw.mem.mask.<m>` assembly op (`pushw.mem` is not supported in masked
+/// form, since there is no existing stack content for its unset lanes to fall back to) to a
+/// sequence that overwrites only the word element positions selected by `mask`, leaving the
+/// remaining top stack elements untouched.
+///
+/// The mask is a 4-bit immediate (set bit `i` selects word element `i`, counting from the top of
+/// the stack). If `mask` is `0b1111`, this degrades to exactly the output of [parse_read_mem] with
+/// `overwrite_stack_top` set to `true`.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `overwrite_stack_top` is `false`, since `pushw.mem` has no prior
+/// stack content to preserve for unset mask lanes.
+fn parse_read_mem_masked(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    overwrite_stack_top: bool,
+    mask: MemMask,
+) -> Result<(), AssemblyError> {
+    if !overwrite_stack_top {
+        return Err(AssemblyError::invalid_op(op));
+    }
+
+    // every lane is selected, so there is nothing to merge: emit the plain unmasked load
+    if mask.bits == 0b1111 {
+        if mask.has_addr {
+            push_mem_addr_at(span_ops, op, 2)?;
+        }
+        span_ops.push(Operation::MLoadW);
+        return Ok(());
+    }
+
+    if mask.has_addr {
+        // address is provided as an immediate; the existing word is already on top of the stack,
+        // so we can duplicate it directly
+        // [v0, v1, v2, v3, ...] => [v0, v1, v2, v3, v0, v1, v2, v3, ...]
+        for _ in 0..4 {
+            span_ops.push(Operation::Dup3);
+        }
+        push_mem_addr_at(span_ops, op, 2)?;
+    } else {
+        // the address is on top of the stack, above the existing word; move it below the word so
+        // the word can be duplicated, then bring it back to the top
+        // [addr, v0, v1, v2, v3, ...] => [v0, v1, v2, v3, addr, ...]
+        span_ops.push(Operation::MovDn4);
+        // => [v0, v1, v2, v3, v0, v1, v2, v3, addr, ...]
+        for _ in 0..4 {
+            span_ops.push(Operation::Dup3);
+        }
+        // => [addr, v0, v1, v2, v3, v0, v1, v2, v3, ...]
+        span_ops.push(Operation::MovUp8);
+    }
+
+    // load over the top (duplicated) word, leaving the original word intact one word deeper
+    // => [L0, L1, L2, L3, v0, v1, v2, v3, ...]
+    span_ops.push(Operation::MLoadW);
+
+    // merge the loaded word (preferred where `mask` is set) with the original word (fallback)
+    merge_masked_word(span_ops, mask.bits);
+
+    Ok(())
+}
+
 /// Translates the `popw.mem` and `storew.mem` assembly ops to the system's `STOREW` memory write
-/// operation.
+/// operation. `popw.local.<idx>` and `storew.local.<idx>` are handled by [parse_write_local]
+/// instead, since a procedure-local index is never optional the way a `.mem` address is.
 ///
 /// If the op provides an address (e.g. `popw.mem.a`), it must be pushed to the stack directly
 /// before the `STOREW` operation. Whether provided directly or via the stack, the memory address
@@ -109,12 +233,17 @@ pub fn parse_read_mem(
 /// leaving the stack unchanged (as required by `storew`) except for the destination memory address,
 /// which is removed by `STOREW`. When `retain_stack_top` is false, values should be dropped from
 /// the stack (as required by `popw`).
+/// Addresses may also be given as a base-plus-offset expression (`popw.mem.base.<offset>` /
+/// `storew.mem.base.<offset>`), where the base comes from the stack and `offset` is folded in at
+/// assembly time.
 ///
 /// This operation takes:
 ///  - popw: 6 VM cycles when the addresses is provided a an immediate value.
 ///  - popw: 5 VM cycles when the address is provided via the stack.
+///  - popw: 7 VM cycles when the address is a base-plus-offset expression.
 ///  - storew: 2 VM cycles  when the addresses is provided a an immediate value.
 ///  - storew: 1 VM cycles  when the address is provided via the stack.
+///  - storew: 3 VM cycles when the address is a base-plus-offset expression.
 ///
 /// # Errors
 ///
@@ -124,7 +253,28 @@ pub fn parse_write_mem(
     span_ops: &mut Vec<Operation>,
     op: &Token,
     retain_stack_top: bool,
+    num_proc_locals: usize,
 ) -> Result<(), AssemblyError> {
+    if is_local_addr(op) {
+        if has_mask(op) {
+            // combining `.local` addressing with `.mask` is not supported
+            return Err(AssemblyError::invalid_op(op));
+        }
+        return parse_write_local(span_ops, op, retain_stack_top, num_proc_locals);
+    }
+
+    if let Some(base) = parse_mem_base(op)? {
+        if has_mask(op) {
+            // combining `.base` addressing with `.mask` is not supported
+            return Err(AssemblyError::invalid_op(op));
+        }
+        return parse_write_mem_base(span_ops, op, retain_stack_top, base);
+    }
+
+    if let Some(mask) = parse_mem_mask(op)? {
+        return parse_write_mem_masked(span_ops, op, retain_stack_top, mask);
+    }
+
     validate_operation!(@only_params op, "popw|storew.mem", 0..1);
 
     if op.num_parts() == 3 {
@@ -140,6 +290,81 @@ pub fn parse_write_mem(
     Ok(())
 }
 
+/// Translates the masked `storew.mem.mask.<m>` assembly op (`popw.mem` is not supported in masked
+/// form, since dropping the new values would leave nothing to merge with the current memory
+/// content) into a read-modify-write sequence: the current word is loaded from memory, the lanes
+/// selected by `mask` are overwritten with the corresponding stack values, and the merged word is
+/// written back, leaving memory at the unselected lanes untouched.
+///
+/// If `mask` is `0b1111`, this degrades to exactly the output of [parse_write_mem] with
+/// `retain_stack_top` set to `true`.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `retain_stack_top` is `false`, since `popw.mem` discards the new
+/// values this operation needs in order to merge them into the existing memory word.
+fn parse_write_mem_masked(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    retain_stack_top: bool,
+    mask: MemMask,
+) -> Result<(), AssemblyError> {
+    if !retain_stack_top {
+        return Err(AssemblyError::invalid_op(op));
+    }
+
+    // every lane is selected, so no memory content survives: emit the plain unmasked store
+    if mask.bits == 0b1111 {
+        if mask.has_addr {
+            push_mem_addr_at(span_ops, op, 2)?;
+        }
+        span_ops.push(Operation::MStoreW);
+        return Ok(());
+    }
+
+    if mask.has_addr {
+        // stack: [v0, v1, v2, v3, ...]
+        // make room for the current memory content, load it in, then bring the new values back
+        // on top so the merge sees them as the preferred word
+        span_ops.push_many(Operation::Pad, 4);
+        push_mem_addr_at(span_ops, op, 2)?;
+        // => [M0, M1, M2, M3, v0, v1, v2, v3, ...]
+        span_ops.push(Operation::MLoadW);
+        // => [v0, v1, v2, v3, M0, M1, M2, M3, ...]
+        span_ops.push(Operation::SwapW);
+
+        // merge the new values (preferred where `mask` is set) with the current memory content
+        // (fallback), then write the merged word back to the same immediate address
+        merge_masked_word(span_ops, mask.bits);
+        push_mem_addr_at(span_ops, op, 2)?;
+    } else {
+        // stack: [addr, v0, v1, v2, v3, ...]
+        // duplicate the address, since it is consumed once to load the current content and again
+        // to write the merged word back
+        span_ops.push(Operation::Dup0);
+        // => [addr, addr, v0, v1, v2, v3, ...]
+        span_ops.push(Operation::Swap);
+        // => [addr, addr, v0, v1, v2, v3, ...] (addr_load on top, addr_store set aside)
+        span_ops.push_many(Operation::Pad, 4);
+        span_ops.push(Operation::MovUp4);
+        // => [addr_load, 0, 0, 0, 0, addr_store, v0, v1, v2, v3, ...]
+        span_ops.push(Operation::MLoadW);
+        // => [M0, M1, M2, M3, addr_store, v0, v1, v2, v3, ...]
+        span_ops.push(Operation::MovUp4);
+        span_ops.push(Operation::MovDn8);
+        // => [M0, M1, M2, M3, v0, v1, v2, v3, addr_store, ...]
+        span_ops.push(Operation::SwapW);
+        // => [v0, v1, v2, v3, M0, M1, M2, M3, addr_store, ...]
+
+        // merge the new values (preferred) with the current memory content (fallback), then bring
+        // the set-aside address back to the top to write the merged word back
+        merge_masked_word(span_ops, mask.bits);
+        span_ops.push(Operation::MovUp4);
+    }
+    span_ops.push(Operation::MStoreW);
+
+    Ok(())
+}
+
 /// Parses a provided memory address and pushes it onto the stack.
 ///
 /// This operation takes 1 VM cycle.
@@ -148,326 +373,1610 @@ pub fn parse_write_mem(
 ///
 /// This function will return an `AssemblyError` if the address parameter does not exist.
 fn push_mem_addr(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
-    let address = parse_element_param(op, 2)?;
-    if address == Felt::ZERO {
+    push_mem_addr_at(span_ops, op, 2)
+}
+
+/// Parses a memory address from `op` at the specified token part index and pushes it onto the
+/// stack. This is the same as [push_mem_addr], but takes the part index as a parameter; the masked
+/// memory ops (e.g. `loadw.mem.5.mask.b1011`) call it with index 2, where their address is found.
+///
+/// This operation takes 1 VM cycle.
+///
+/// # Errors
+///
+/// This function will return an `AssemblyError` if the address parameter does not exist.
+fn push_mem_addr_at(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    part_index: usize,
+) -> Result<(), AssemblyError> {
+    let address = parse_element_param(op, part_index)?;
+    push_addr_immediate(span_ops, address.as_int());
+
+    Ok(())
+}
+
+/// Pushes the address `addr` onto the stack as an immediate value.
+fn push_addr_immediate(span_ops: &mut Vec<Operation>, addr: u64) {
+    if addr == 0 {
         span_ops.push(Operation::Pad);
     } else {
-        span_ops.push(Operation::Push(address));
+        span_ops.push(Operation::Push(Felt::new(addr)));
     }
-
-    Ok(())
 }
 
-// TESTS
+// BULK MEMORY PSEUDO-OPS
 // ================================================================================================
 
-#[cfg(test)]
-mod tests {
-    use super::{
-        super::{
-            parse_loadw, parse_pop, parse_popw, parse_push, parse_pushw, parse_storew,
-            tests::get_parsing_error, Felt,
-        },
-        AssemblyError, Operation, Token,
-    };
+/// Translates the `mem.copy.<src>.<dst>.<n>` pseudo-op into an unrolled sequence of word loads
+/// and stores which copies `n` consecutive words from the range starting at `src` to the range
+/// starting at `dst`. `src`, `dst`, and `n` must all be immediate values, since a SPAN block
+/// cannot contain loops and the copy must therefore be fully unrolled at assembly time.
+///
+/// If the source and destination ranges overlap, the words are copied in whichever address order
+/// (ascending or descending) guarantees a word is read before it is overwritten, mirroring the
+/// semantics of `memmove`.
+///
+/// This operation takes `8 * n` VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `src`, `dst`, or `n` is missing or not a valid immediate value,
+/// or if extra parameters are provided.
+pub fn parse_mem_copy(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+    if op.num_parts() > 5 {
+        return Err(AssemblyError::extra_param(op));
+    }
 
-    // TESTS FOR PUSHING VALUES ONTO THE STACK (PUSH)
-    // ============================================================================================
+    let src = parse_element_param(op, 2)?.as_int();
+    let dst = parse_element_param(op, 3)?.as_int();
+    let n = parse_element_param(op, 4)?.as_int();
 
-    #[test]
-    fn push_mem() {
-        let num_proc_locals = 0;
-        // reads the first element of the word from memory and pushes it onto the stack
+    // copying in ascending order is safe unless a word would be overwritten before it has been
+    // read, which can only happen when the destination range starts after the source range
+    if dst <= src {
+        for i in 0..n {
+            copy_one_word(span_ops, src + i, dst + i);
+        }
+    } else {
+        for i in (0..n).rev() {
+            copy_one_word(span_ops, src + i, dst + i);
+        }
+    }
 
-        // test push with memory address on top of stack
-        let mut span_ops: Vec<Operation> = Vec::new();
-        let op_push = Token::new("push.mem", 0);
-        let expected = vec![Operation::MLoad];
+    Ok(())
+}
 
-        parse_push(&mut span_ops, &op_push, num_proc_locals).expect("Failed to parse push.mem");
+/// Emits the load/store sequence which copies a single word from `src_addr` to `dst_addr`,
+/// dropping the loaded word once it has been written back out.
+fn copy_one_word(span_ops: &mut Vec<Operation>, src_addr: u64, dst_addr: u64) {
+    push_addr_immediate(span_ops, src_addr);
+    span_ops.push(Operation::MLoadW);
+    push_addr_immediate(span_ops, dst_addr);
+    span_ops.push(Operation::MStoreW);
+    span_ops.push_many(Operation::Drop, 4);
+}
 
-        assert_eq!(&span_ops, &expected);
+/// Translates the `mem.fill.<addr>.<n>` pseudo-op into an unrolled sequence of word stores which
+/// writes the word on top of the stack to each of the `n` consecutive addresses starting at
+/// `addr`, consuming the word once all `n` stores are complete. `addr` and `n` must both be
+/// immediate values, for the same reason as in [parse_mem_copy].
+///
+/// This operation takes `2 * n + 4` VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `addr` or `n` is missing or not a valid immediate value, or if
+/// extra parameters are provided.
+pub fn parse_mem_fill(span_ops: &mut Vec<Operation>, op: &Token) -> Result<(), AssemblyError> {
+    if op.num_parts() > 4 {
+        return Err(AssemblyError::extra_param(op));
+    }
 
-        // test push with memory address provided directly (address 0)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_push_addr = Token::new("push.mem.0", 0);
-        let expected_addr = vec![Operation::Pad, Operation::MLoad];
+    let addr = parse_element_param(op, 2)?.as_int();
+    let n = parse_element_param(op, 3)?.as_int();
 
-        parse_push(&mut span_ops_addr, &op_push_addr, num_proc_locals)
-            .expect("Failed to parse push.mem.0 (address provided by op)");
+    for i in 0..n {
+        push_addr_immediate(span_ops, addr + i);
+        span_ops.push(Operation::MStoreW);
+    }
+    span_ops.push_many(Operation::Drop, 4);
 
-        assert_eq!(&span_ops_addr, &expected_addr);
+    Ok(())
+}
 
-        // test push with memory address provided directly (address 2)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_push_addr = Token::new("push.mem.2", 0);
-        let expected_addr = vec![Operation::Push(Felt::new(2)), Operation::MLoad];
+// PROCEDURE-LOCAL ADDRESSING
+// ================================================================================================
 
-        parse_push(&mut span_ops_addr, &op_push_addr, num_proc_locals)
-            .expect("Failed to parse push.mem.2 (address provided by op)");
+/// Returns true if `op`'s address operand is given as a procedure-local index (e.g.
+/// `push.local.3`) rather than a `.mem` address.
+fn is_local_addr(op: &Token) -> bool {
+    op.parts().get(1).copied() == Some("local")
+}
 
-        assert_eq!(&span_ops_addr, &expected_addr);
-    }
+/// Returns true if `op` has a `mask` part anywhere in it.
+fn has_mask(op: &Token) -> bool {
+    op.parts().iter().any(|&part| part == "mask")
+}
 
-    #[test]
-    fn push_mem_invalid() {
-        test_parse_mem("push");
+/// Parses and validates a procedure-local index from `op`'s third part, checking it against the
+/// number of locals declared by the enclosing procedure.
+///
+/// # Errors
+/// Returns an `AssemblyError` if the index is missing, not a valid immediate value, or is out of
+/// range for the enclosing procedure's declared local count.
+fn parse_local_index(op: &Token, num_proc_locals: usize) -> Result<u64, AssemblyError> {
+    let index = parse_element_param(op, 2)?.as_int();
+    if index >= num_proc_locals as u64 {
+        return Err(AssemblyError::local_index_out_of_bounds(
+            op,
+            index,
+            num_proc_locals,
+        ));
     }
 
-    #[test]
-    fn pushw_mem() {
-        let num_proc_locals = 0;
-        // reads a word from memory and pushes it onto the stack
-
-        // test push with memory address on top of stack
-        let mut span_ops: Vec<Operation> = Vec::new();
-        let op_push = Token::new("pushw.mem", 0);
-        let expected = vec![
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Pad,
-            Operation::MovUp4,
-            Operation::MLoadW,
-        ];
+    Ok(index)
+}
 
-        parse_pushw(&mut span_ops, &op_push, num_proc_locals).expect("Failed to parse pushw.mem");
+/// Pushes the absolute memory address of procedure-local `index`, computed as the current frame
+/// pointer (`fmp`) plus `index`.
+fn push_local_addr(span_ops: &mut Vec<Operation>, index: u64) {
+    push_addr_immediate(span_ops, index);
+    span_ops.push(Operation::FmpAdd);
+}
 
-        assert_eq!(&span_ops, &expected);
+/// Translates `push.local.<idx>` and `pop.local.<idx>` to a frame-relative memory access.
+///
+/// This operation takes 3 VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `idx` is missing, invalid, out of range, or if extra parameters
+/// are provided.
+fn parse_push_local(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    num_proc_locals: usize,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 3 {
+        return Err(AssemblyError::extra_param(op));
+    }
 
-        // test push with memory address provided directly (address 0)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_push_addr = Token::new("pushw.mem.0", 0);
-        let expected_addr = vec![
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Pad,
-            Operation::MLoadW,
-        ];
+    let index = parse_local_index(op, num_proc_locals)?;
+    push_local_addr(span_ops, index);
+    span_ops.push(Operation::MLoad);
 
-        parse_pushw(&mut span_ops_addr, &op_push_addr, num_proc_locals)
-            .expect("Failed to parse pushw.mem.0 (address provided by op)");
+    Ok(())
+}
 
-        assert_eq!(&span_ops_addr, &expected_addr);
+/// See [parse_push_local]. Stores the top of the stack at procedure-local `idx` and drops it.
+///
+/// This operation takes 4 VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `idx` is missing, invalid, out of range, or if extra parameters
+/// are provided.
+fn parse_pop_local(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    num_proc_locals: usize,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 3 {
+        return Err(AssemblyError::extra_param(op));
+    }
 
-        // test push with memory address provided directly (address 2)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_push_addr = Token::new("pushw.mem.2", 0);
-        let expected_addr = vec![
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Pad,
-            Operation::Push(Felt::new(2)),
-            Operation::MLoadW,
-        ];
+    let index = parse_local_index(op, num_proc_locals)?;
+    push_local_addr(span_ops, index);
+    span_ops.push(Operation::MStore);
+    span_ops.push(Operation::Drop);
 
-        parse_pushw(&mut span_ops_addr, &op_push_addr, num_proc_locals)
-            .expect("Failed to parse pushw.mem.2 (address provided by op)");
+    Ok(())
+}
 
-        assert_eq!(&span_ops_addr, &expected_addr);
+/// Translates `pushw.local.<idx>` and `loadw.local.<idx>` to a frame-relative word load. Behaves
+/// the same as [parse_read_mem] with respect to `overwrite_stack_top`, except the address is
+/// always a procedure-local index rather than optional.
+///
+/// This operation takes 3 VM cycles for `loadw.local`, 7 VM cycles for `pushw.local`.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `idx` is missing, invalid, out of range, or if extra parameters
+/// are provided.
+fn parse_read_local(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    overwrite_stack_top: bool,
+    num_proc_locals: usize,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 3 {
+        return Err(AssemblyError::extra_param(op));
     }
 
-    #[test]
-    fn pushw_mem_invalid() {
-        test_parse_mem("pushw");
+    let index = parse_local_index(op, num_proc_locals)?;
+    if !overwrite_stack_top {
+        span_ops.push_many(Operation::Pad, 4);
     }
+    push_local_addr(span_ops, index);
+    span_ops.push(Operation::MLoadW);
 
-    // TESTS FOR REMOVING VALUES FROM THE STACK (POP)
-    // ============================================================================================
+    Ok(())
+}
 
-    #[test]
-    fn pop_mem_invalid() {
-        test_parse_mem("pop");
+/// Translates `popw.local.<idx>` and `storew.local.<idx>` to a frame-relative word store. Behaves
+/// the same as [parse_write_mem] with respect to `retain_stack_top`, except the address is always
+/// a procedure-local index rather than optional.
+///
+/// This operation takes 3 VM cycles for `storew.local`, 7 VM cycles for `popw.local`.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `idx` is missing, invalid, out of range, or if extra parameters
+/// are provided.
+fn parse_write_local(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    retain_stack_top: bool,
+    num_proc_locals: usize,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 3 {
+        return Err(AssemblyError::extra_param(op));
+    }
+
+    let index = parse_local_index(op, num_proc_locals)?;
+    push_local_addr(span_ops, index);
+    span_ops.push(Operation::MStoreW);
+
+    if !retain_stack_top {
+        span_ops.push_many(Operation::Drop, 4);
+    }
+
+    Ok(())
+}
+
+// MASKED WORD ACCESS
+// ================================================================================================
+
+/// A parsed `mask.<m>` suffix for a masked `.mem` word instruction.
+struct MemMask {
+    /// The 4-bit mask; set bit `i` selects word element `i` (counting from the top of the stack).
+    bits: u8,
+    /// Whether the op also provides an immediate memory address ahead of the `mask` keyword
+    /// (e.g. `loadw.mem.5.mask.b1011` vs. `loadw.mem.mask.b1011`).
+    has_addr: bool,
+}
+
+/// Scans `op` for a `mask.<m>` suffix and parses it if present.
+///
+/// The mask value may be given as a 4-character binary literal prefixed with `b` (e.g. `b1011`)
+/// or as a decimal value in `0..=15`.
+///
+/// Returns `None` if `op` has no `mask` part, so callers can fall back to unmasked parsing.
+///
+/// # Errors
+/// Returns an `AssemblyError` if a `mask` keyword is present but its value is missing or invalid.
+fn parse_mem_mask(op: &Token) -> Result<Option<MemMask>, AssemblyError> {
+    let parts = op.parts();
+    let mask_idx = match parts.iter().position(|&part| part == "mask") {
+        Some(idx) => idx,
+        None => return Ok(None),
+    };
+
+    let mask_value = parts
+        .get(mask_idx + 1)
+        .ok_or_else(|| AssemblyError::invalid_param(op, mask_idx + 1))?;
+
+    let bits = if let Some(bin) = mask_value.strip_prefix('b') {
+        if bin.len() != 4 || !bin.chars().all(|c| c == '0' || c == '1') {
+            return Err(AssemblyError::invalid_param(op, mask_idx + 1));
+        }
+        u8::from_str_radix(bin, 2).expect("invalid binary mask literal")
+    } else {
+        mask_value
+            .parse::<u8>()
+            .map_err(|_| AssemblyError::invalid_param(op, mask_idx + 1))?
+    };
+
+    if bits > 0b1111 {
+        return Err(AssemblyError::invalid_param(op, mask_idx + 1));
+    }
+
+    // `mask` is expected at part index 2 (no address) or 3 (address at index 2)
+    let has_addr = match mask_idx {
+        2 => false,
+        3 => true,
+        _ => return Err(AssemblyError::invalid_param(op, mask_idx)),
+    };
+
+    Ok(Some(MemMask { bits, has_addr }))
+}
+
+/// Merges two words at the top of the stack into one, consuming both. The top word is preferred
+/// for lanes where the corresponding bit of `mask` is set; the second word is used for lanes
+/// where it is clear. Bit `i` of `mask` corresponds to the word element `i` positions from the
+/// top of the stack (e.g. bit 0 picks between the very top elements of each word).
+///
+/// Before: `[P0, P1, P2, P3, Q0, Q1, Q2, Q3, ...]`
+/// After:  `[(P0 or Q0), (P1 or Q1), (P2 or Q2), (P3 or Q3), ...]`
+fn merge_masked_word(span_ops: &mut Vec<Operation>, mask: u8) {
+    debug_assert!(mask <= 0b1111);
+
+    // process lanes from the bottom of the word (lane 3) to the top (lane 0), so that each
+    // resolved lane can be tucked below the still-unresolved ones without disturbing lanes that
+    // were already resolved
+    for lane in (0..4).rev() {
+        // bring this lane's fallback element (always at relative index 4) next to its preferred
+        // element (always at relative index `lane`, the bottom of the remaining preferred group)
+        span_ops.push(move_up(4 + lane));
+        span_ops.push(move_up(lane + 1));
+
+        // keep whichever element is selected by `mask`, dropping the other
+        if mask & (1 << lane) != 0 {
+            span_ops.push(Operation::Swap);
+            span_ops.push(Operation::Drop);
+        } else {
+            span_ops.push(Operation::Drop);
+        }
+
+        // tuck the resolved lane below the remaining preferred-group elements
+        if lane > 0 {
+            span_ops.push(move_down(lane));
+        }
+    }
+}
+
+/// Returns the `MovUpN` operation for the given `n`, using `Swap` for `n == 1`.
+fn move_up(n: usize) -> Operation {
+    match n {
+        1 => Operation::Swap,
+        2 => Operation::MovUp2,
+        3 => Operation::MovUp3,
+        4 => Operation::MovUp4,
+        5 => Operation::MovUp5,
+        6 => Operation::MovUp6,
+        7 => Operation::MovUp7,
+        _ => unreachable!("merge_masked_word only needs MovUp up to 7"),
+    }
+}
+
+/// Returns the `MovDnN` operation for the given `n`, using `Swap` for `n == 1`.
+fn move_down(n: usize) -> Operation {
+    match n {
+        1 => Operation::Swap,
+        2 => Operation::MovDn2,
+        3 => Operation::MovDn3,
+        _ => unreachable!("merge_masked_word only needs MovDn up to 3"),
+    }
+}
+
+// BASE-PLUS-OFFSET (INDEXED) ADDRESSING
+// ================================================================================================
+
+/// A parsed `base.<offset>` suffix for indexed `.mem` addressing.
+struct MemBase {
+    /// The compile-time immediate offset to fold into the runtime base address.
+    offset: u64,
+}
+
+/// Scans `op` for a `base.<offset>` suffix and parses it if present.
+///
+/// `base.<offset>` addressing takes the runtime base address from the top of the stack and adds
+/// the immediate `offset` to it at assembly time, via an emitted `Push`/`Add` pair, before the
+/// resulting address is consumed by the memory op. This lets loop-generated code index into an
+/// array by keeping a base pointer on the stack while the assembler folds in each element's
+/// constant offset, rather than forcing the caller to precompute every address.
+///
+/// Returns `None` if `op` has no `base` part, so callers can fall back to ordinary parsing.
+///
+/// # Errors
+/// Returns an `AssemblyError` if a `base` keyword is present but its offset is missing or not a
+/// valid immediate value.
+fn parse_mem_base(op: &Token) -> Result<Option<MemBase>, AssemblyError> {
+    if op.parts().get(2).copied() != Some("base") {
+        return Ok(None);
+    }
+
+    let offset = parse_element_param(op, 3)?.as_int();
+    Ok(Some(MemBase { offset }))
+}
+
+/// Emits the `Push`/`Add` pair which folds `base.offset` into the runtime base address already on
+/// top of the stack, leaving the computed `base + offset` address in its place.
+///
+/// This operation takes 2 VM cycles.
+fn apply_base_offset(span_ops: &mut Vec<Operation>, base: MemBase) {
+    push_addr_immediate(span_ops, base.offset);
+    span_ops.push(Operation::Add);
+}
+
+/// Translates `push.mem.base.<offset>` to a base-plus-offset memory read: the base address is
+/// taken from the stack and `offset` is folded into it before `MLOAD` consumes the result.
+///
+/// This operation takes 3 VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if extra parameters are provided.
+fn parse_push_mem_base(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    base: MemBase,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 4 {
+        return Err(AssemblyError::extra_param(op));
+    }
+
+    apply_base_offset(span_ops, base);
+    span_ops.push(Operation::MLoad);
+
+    Ok(())
+}
+
+/// Translates `pop.mem.base.<offset>` to a base-plus-offset memory write: the base address is
+/// taken from the stack and `offset` is folded into it before `MSTORE` consumes the result and the
+/// stored value is dropped.
+///
+/// This operation takes 4 VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if extra parameters are provided.
+fn parse_pop_mem_base(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    base: MemBase,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 4 {
+        return Err(AssemblyError::extra_param(op));
+    }
+
+    apply_base_offset(span_ops, base);
+    span_ops.push(Operation::MStore);
+    span_ops.push(Operation::Drop);
+
+    Ok(())
+}
+
+/// Translates `pushw.mem.base.<offset>` and `loadw.mem.base.<offset>` to a base-plus-offset word
+/// read. The base address is brought to the top of the stack first for `pushw` (which must also
+/// make room for the loaded word); for `loadw` it is already on top. Either way, `offset` is
+/// folded into it before `LOADW` consumes the result.
+///
+/// This operation takes:
+///  - pushw: 8 VM cycles.
+///  - loadw: 3 VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if extra parameters are provided.
+fn parse_read_mem_base(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    overwrite_stack_top: bool,
+    base: MemBase,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 4 {
+        return Err(AssemblyError::extra_param(op));
+    }
+
+    if !overwrite_stack_top {
+        // make space for the new elements, then bring the base address to the top
+        span_ops.push_many(Operation::Pad, 4);
+        span_ops.push(Operation::MovUp4);
+    }
+
+    apply_base_offset(span_ops, base);
+    span_ops.push(Operation::MLoadW);
+
+    Ok(())
+}
+
+/// Translates `popw.mem.base.<offset>` and `storew.mem.base.<offset>` to a base-plus-offset word
+/// write. The base address on top of the stack has `offset` folded into it before `STOREW`
+/// consumes the result; `popw` additionally drops the stored word afterward.
+///
+/// This operation takes:
+///  - popw: 7 VM cycles.
+///  - storew: 3 VM cycles.
+///
+/// # Errors
+/// Returns an `AssemblyError` if extra parameters are provided.
+fn parse_write_mem_base(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    retain_stack_top: bool,
+    base: MemBase,
+) -> Result<(), AssemblyError> {
+    if op.num_parts() > 4 {
+        return Err(AssemblyError::extra_param(op));
+    }
+
+    apply_base_offset(span_ops, base);
+    span_ops.push(Operation::MStoreW);
+
+    if !retain_stack_top {
+        span_ops.push_many(Operation::Drop, 4);
+    }
+
+    Ok(())
+}
+
+// BUILT-IN PARSER REGISTRATIONS
+// ================================================================================================
+
+/// Builds an [OpParserRegistry] with the built-in `.mem`-family instruction parsers (`push.mem`,
+/// `pop.mem`, `pushw.mem`/`loadw.mem`, `popw.mem`/`storew.mem`, and `mem.copy`/`mem.fill`)
+/// registered, and [parse_mem_op] as the single entry point that dispatches through it.
+///
+/// Unlike the first attempt at this, these wrapper parsers are the only way [parse_mem_op] reaches
+/// `parse_push_mem`/`parse_pop_mem`/`parse_read_mem`/`parse_write_mem`/`parse_mem_copy`/
+/// `parse_mem_fill` -- there is no special-cased fallback path left in this module.
+fn builtin_mem_op_registry() -> OpParserRegistry {
+    let mut registry = OpParserRegistry::new();
+    registry.register(Box::new(PushMemParser));
+    registry.register(Box::new(PopMemParser));
+    registry.register(Box::new(ReadMemParser {
+        overwrite_stack_top: false,
+    }));
+    registry.register(Box::new(ReadMemParser {
+        overwrite_stack_top: true,
+    }));
+    registry.register(Box::new(WriteMemParser {
+        retain_stack_top: false,
+    }));
+    registry.register(Box::new(WriteMemParser {
+        retain_stack_top: true,
+    }));
+    registry.register(Box::new(MemBulkParser));
+    registry
+}
+
+/// Parses `op` as one of the built-in `.mem`-family instructions, dispatching through a freshly
+/// built [OpParserRegistry] (see [builtin_mem_op_registry]) rather than matching on `op`'s prefix
+/// directly.
+///
+/// This is the entry point a top-level instruction dispatcher should call for `push`, `pop`,
+/// `pushw`, `popw`, `loadw`, `storew`, and `mem` instructions in place of calling
+/// `parse_push_mem`/`parse_pop_mem`/etc. directly.
+///
+/// # Errors
+/// Returns an `AssemblyError` if `op`'s first part doesn't match one of the prefixes above, or if
+/// the matched parser fails to parse `op`.
+pub fn parse_mem_op(
+    span_ops: &mut Vec<Operation>,
+    op: &Token,
+    num_proc_locals: usize,
+) -> Result<(), AssemblyError> {
+    builtin_mem_op_registry().parse(span_ops, op, num_proc_locals)
+}
+
+struct PushMemParser;
+
+impl OpParser for PushMemParser {
+    fn prefixes(&self) -> &[&str] {
+        &["push"]
+    }
+
+    fn parse(
+        &self,
+        span_ops: &mut Vec<Operation>,
+        op: &Token,
+        num_proc_locals: usize,
+    ) -> Result<(), AssemblyError> {
+        parse_push_mem(span_ops, op, num_proc_locals)
+    }
+}
+
+struct PopMemParser;
+
+impl OpParser for PopMemParser {
+    fn prefixes(&self) -> &[&str] {
+        &["pop"]
+    }
+
+    fn parse(
+        &self,
+        span_ops: &mut Vec<Operation>,
+        op: &Token,
+        num_proc_locals: usize,
+    ) -> Result<(), AssemblyError> {
+        parse_pop_mem(span_ops, op, num_proc_locals)
+    }
+}
+
+struct ReadMemParser {
+    overwrite_stack_top: bool,
+}
+
+impl OpParser for ReadMemParser {
+    fn prefixes(&self) -> &[&str] {
+        if self.overwrite_stack_top {
+            &["loadw"]
+        } else {
+            &["pushw"]
+        }
+    }
+
+    fn parse(
+        &self,
+        span_ops: &mut Vec<Operation>,
+        op: &Token,
+        num_proc_locals: usize,
+    ) -> Result<(), AssemblyError> {
+        parse_read_mem(span_ops, op, self.overwrite_stack_top, num_proc_locals)
+    }
+}
+
+struct WriteMemParser {
+    retain_stack_top: bool,
+}
+
+impl OpParser for WriteMemParser {
+    fn prefixes(&self) -> &[&str] {
+        if self.retain_stack_top {
+            &["storew"]
+        } else {
+            &["popw"]
+        }
+    }
+
+    fn parse(
+        &self,
+        span_ops: &mut Vec<Operation>,
+        op: &Token,
+        num_proc_locals: usize,
+    ) -> Result<(), AssemblyError> {
+        parse_write_mem(span_ops, op, self.retain_stack_top, num_proc_locals)
+    }
+}
+
+struct MemBulkParser;
+
+impl OpParser for MemBulkParser {
+    fn prefixes(&self) -> &[&str] {
+        &["mem"]
+    }
+
+    fn parse(
+        &self,
+        span_ops: &mut Vec<Operation>,
+        op: &Token,
+        _num_proc_locals: usize,
+    ) -> Result<(), AssemblyError> {
+        match op.parts().get(1).copied() {
+            Some("copy") => parse_mem_copy(span_ops, op),
+            Some("fill") => parse_mem_fill(span_ops, op),
+            _ => Err(AssemblyError::invalid_op(op)),
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{
+            parse_loadw, parse_pop, parse_popw, parse_push, parse_pushw, parse_storew,
+            tests::get_parsing_error, Felt,
+        },
+        AssemblyError, Operation, Token,
+    };
+
+    // TESTS FOR PUSHING VALUES ONTO THE STACK (PUSH)
+    // ============================================================================================
+
+    #[test]
+    fn push_mem() {
+        let num_proc_locals = 0;
+        // reads the first element of the word from memory and pushes it onto the stack
+
+        // test push with memory address on top of stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_push = Token::new("push.mem", 0);
+        let expected = vec![Operation::MLoad];
+
+        parse_push(&mut span_ops, &op_push, num_proc_locals).expect("Failed to parse push.mem");
+
+        assert_eq!(&span_ops, &expected);
+
+        // test push with memory address provided directly (address 0)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_push_addr = Token::new("push.mem.0", 0);
+        let expected_addr = vec![Operation::Pad, Operation::MLoad];
+
+        parse_push(&mut span_ops_addr, &op_push_addr, num_proc_locals)
+            .expect("Failed to parse push.mem.0 (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+
+        // test push with memory address provided directly (address 2)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_push_addr = Token::new("push.mem.2", 0);
+        let expected_addr = vec![Operation::Push(Felt::new(2)), Operation::MLoad];
+
+        parse_push(&mut span_ops_addr, &op_push_addr, num_proc_locals)
+            .expect("Failed to parse push.mem.2 (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+    }
+
+    #[test]
+    fn push_mem_invalid() {
+        test_parse_mem("push");
+    }
+
+    #[test]
+    fn pushw_mem() {
+        let num_proc_locals = 0;
+        // reads a word from memory and pushes it onto the stack
+
+        // test push with memory address on top of stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_push = Token::new("pushw.mem", 0);
+        let expected = vec![
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::MovUp4,
+            Operation::MLoadW,
+        ];
+
+        parse_pushw(&mut span_ops, &op_push, num_proc_locals).expect("Failed to parse pushw.mem");
+
+        assert_eq!(&span_ops, &expected);
+
+        // test push with memory address provided directly (address 0)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_push_addr = Token::new("pushw.mem.0", 0);
+        let expected_addr = vec![
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::MLoadW,
+        ];
+
+        parse_pushw(&mut span_ops_addr, &op_push_addr, num_proc_locals)
+            .expect("Failed to parse pushw.mem.0 (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+
+        // test push with memory address provided directly (address 2)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_push_addr = Token::new("pushw.mem.2", 0);
+        let expected_addr = vec![
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Push(Felt::new(2)),
+            Operation::MLoadW,
+        ];
+
+        parse_pushw(&mut span_ops_addr, &op_push_addr, num_proc_locals)
+            .expect("Failed to parse pushw.mem.2 (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+    }
+
+    #[test]
+    fn pushw_mem_invalid() {
+        test_parse_mem("pushw");
+    }
+
+    // TESTS FOR REMOVING VALUES FROM THE STACK (POP)
+    // ============================================================================================
+
+    #[test]
+    fn pop_mem_invalid() {
+        test_parse_mem("pop");
+    }
+
+    #[test]
+    fn pop_mem() {
+        let num_proc_locals = 0;
+
+        // stores top element of the stack in memory
+        // then removes this element from the top of the stack
+
+        // test pop with memory address on top of the stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_mem_pop = Token::new("pop.mem", 0);
+        let expected = vec![Operation::MStore, Operation::Drop];
+        parse_pop(&mut span_ops, &op_mem_pop, num_proc_locals).expect("Failed to parse pop.mem");
+        assert_eq!(&span_ops, &expected);
+
+        // test pop with memory address provided directly (address 0)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_pop_addr = Token::new("pop.mem.0", 0);
+        let expected_addr = vec![Operation::Pad, Operation::MStore, Operation::Drop];
+
+        parse_pop(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
+            .expect("Failed to parse pop.mem.0");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+
+        // test pop with memory address provided directly (address 2)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_pop_addr = Token::new("pop.mem.2", 0);
+        let expected_addr = vec![
+            Operation::Push(Felt::new(2)),
+            Operation::MStore,
+            Operation::Drop,
+        ];
+
+        parse_pop(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
+            .expect("Failed to parse pop.mem.2");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+    }
+
+    #[test]
+    fn popw_mem() {
+        let num_proc_locals = 0;
+
+        // stores the top 4 elements of the stack in memory
+        // then removes those 4 elements from the top of the stack
+
+        // test pop with memory address on top of the stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_mem_pop = Token::new("popw.mem", 0);
+        let expected = vec![
+            Operation::MStoreW,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+        ];
+        parse_popw(&mut span_ops, &op_mem_pop, num_proc_locals).expect("Failed to parse popw.mem");
+        assert_eq!(&span_ops, &expected);
+
+        // test pop with memory address provided directly (address 0)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_pop_addr = Token::new("popw.mem.0", 0);
+        let expected_addr = vec![
+            Operation::Pad,
+            Operation::MStoreW,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+        ];
+
+        parse_popw(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
+            .expect("Failed to parse popw.mem.0");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+
+        // test pop with memory address provided directly (address 2)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_pop_addr = Token::new("popw.mem.2", 0);
+        let expected_addr = vec![
+            Operation::Push(Felt::new(2)),
+            Operation::MStoreW,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+        ];
+
+        parse_popw(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
+            .expect("Failed to parse popw.mem.2");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+    }
+
+    #[test]
+    fn popw_mem_invalid() {
+        test_parse_mem("popw");
+    }
+
+    // TESTS FOR OVERWRITING VALUES ON THE STACK (LOAD)
+    // ============================================================================================
+
+    #[test]
+    fn loadw_mem() {
+        let num_proc_locals = 0;
+
+        // reads a word from memory and overwrites the top 4 stack elements
+
+        // test load with memory address on top of stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_push = Token::new("loadw.mem", 0);
+        let expected = vec![Operation::MLoadW];
+
+        parse_loadw(&mut span_ops, &op_push, num_proc_locals).expect("Failed to parse loadw.mem");
+
+        assert_eq!(&span_ops, &expected);
+
+        // test load with memory address provided directly (address 0)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_load_addr = Token::new("loadw.mem.0", 0);
+        let expected_addr = vec![Operation::Pad, Operation::MLoadW];
+
+        parse_loadw(&mut span_ops_addr, &op_load_addr, num_proc_locals)
+            .expect("Failed to parse loadw.mem.0 (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+
+        // test load with memory address provided directly (address 2)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_load_addr = Token::new("loadw.mem.2", 0);
+        let expected_addr = vec![Operation::Push(Felt::new(2)), Operation::MLoadW];
+
+        parse_loadw(&mut span_ops_addr, &op_load_addr, num_proc_locals)
+            .expect("Failed to parse loadw.mem.2 (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+    }
+
+    #[test]
+    fn loadw_mem_invalid() {
+        test_parse_mem("loadw");
+    }
+
+    #[test]
+    fn loadw_mem_masked() {
+        let num_proc_locals = 0;
+
+        // masked load with memory address provided directly (address 2); only lanes 0 and 2 are
+        // overwritten from memory, lanes 1 and 3 keep their prior stack values
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_load = Token::new("loadw.mem.2.mask.b0101", 0);
+        let expected = vec![
+            Operation::Dup3,
+            Operation::Dup3,
+            Operation::Dup3,
+            Operation::Dup3,
+            Operation::Push(Felt::new(2)),
+            Operation::MLoadW,
+            Operation::MovUp7,
+            Operation::MovUp4,
+            Operation::Drop,
+            Operation::MovDn3,
+            Operation::MovUp6,
+            Operation::MovUp3,
+            Operation::Swap,
+            Operation::Drop,
+            Operation::MovDn2,
+            Operation::MovUp5,
+            Operation::MovUp2,
+            Operation::Drop,
+            Operation::Swap,
+            Operation::MovUp4,
+            Operation::Swap,
+            Operation::Swap,
+            Operation::Drop,
+        ];
+
+        parse_loadw(&mut span_ops, &op_load, num_proc_locals)
+            .expect("Failed to parse loadw.mem.2.mask.b0101");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn loadw_mem_masked_full_matches_unmasked() {
+        let num_proc_locals = 0;
+
+        // a fully-set mask must degrade to exactly the unmasked loadw.mem output
+        let mut span_ops_masked: Vec<Operation> = Vec::new();
+        let op_masked = Token::new("loadw.mem.2.mask.b1111", 0);
+        parse_loadw(&mut span_ops_masked, &op_masked, num_proc_locals)
+            .expect("Failed to parse loadw.mem.2.mask.b1111");
+
+        let mut span_ops_unmasked: Vec<Operation> = Vec::new();
+        let op_unmasked = Token::new("loadw.mem.2", 0);
+        parse_loadw(&mut span_ops_unmasked, &op_unmasked, num_proc_locals)
+            .expect("Failed to parse loadw.mem.2");
+
+        assert_eq!(span_ops_masked, span_ops_unmasked);
+    }
+
+    #[test]
+    fn loadw_mem_masked_invalid() {
+        let num_proc_locals = 0;
+
+        // pushw.mem has no prior stack content for unset mask lanes to fall back to
+        let op_str = "pushw.mem.mask.b0101";
+        let op = Token::new(op_str, 0);
+        let expected = AssemblyError::invalid_op(&op);
+        assert_eq!(
+            get_parsing_error("pushw", &op, num_proc_locals),
+            expected
+        );
+
+        // mask value out of range
+        let op_str = "loadw.mem.mask.16";
+        let op = Token::new(op_str, 0);
+        let expected = AssemblyError::invalid_param(&op, 3);
+        assert_eq!(
+            get_parsing_error("loadw", &op, num_proc_locals),
+            expected
+        );
+
+        // missing mask value
+        let op_str = "loadw.mem.mask";
+        let op = Token::new(op_str, 0);
+        let expected = AssemblyError::invalid_param(&op, 3);
+        assert_eq!(
+            get_parsing_error("loadw", &op, num_proc_locals),
+            expected
+        );
+    }
+
+    // TESTS FOR SAVING STACK VALUES WITHOUT REMOVING THEM (STORE)
+    // ============================================================================================
+
+    #[test]
+    fn storew_mem() {
+        let num_proc_locals = 0;
+        // stores the top 4 elements of the stack in memory
+
+        // test store with memory address on top of the stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_store = Token::new("storew.mem", 0);
+        let expected = vec![Operation::MStoreW];
+
+        parse_storew(&mut span_ops, &op_store, num_proc_locals)
+            .expect("Failed to parse storew.mem");
+
+        assert_eq!(&span_ops, &expected);
+
+        // test store with memory address provided directly (address 0)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_store_addr = Token::new("storew.mem.0", 0);
+        let expected_addr = vec![Operation::Pad, Operation::MStoreW];
+
+        parse_storew(&mut span_ops_addr, &op_store_addr, num_proc_locals)
+            .expect("Failed to parse storew.mem.0 with adddress (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+
+        // test store with memory address provided directly (address 2)
+        let mut span_ops_addr: Vec<Operation> = Vec::new();
+        let op_store_addr = Token::new("storew.mem.2", 0);
+        let expected_addr = vec![Operation::Push(Felt::new(2)), Operation::MStoreW];
+
+        parse_storew(&mut span_ops_addr, &op_store_addr, num_proc_locals)
+            .expect("Failed to parse storew.mem.2 with adddress (address provided by op)");
+
+        assert_eq!(&span_ops_addr, &expected_addr);
+    }
+
+    #[test]
+    fn storew_mem_masked() {
+        let num_proc_locals = 0;
+
+        // masked store with memory address on top of the stack; only lanes 1 and 3 are
+        // overwritten in memory, the rest of the memory word is preserved
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op_store = Token::new("storew.mem.mask.b1010", 0);
+        let expected = vec![
+            Operation::Dup0,
+            Operation::Swap,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::MovUp4,
+            Operation::MLoadW,
+            Operation::MovUp4,
+            Operation::MovDn8,
+            Operation::SwapW,
+            Operation::MovUp7,
+            Operation::MovUp4,
+            Operation::Swap,
+            Operation::Drop,
+            Operation::MovDn3,
+            Operation::MovUp6,
+            Operation::MovUp3,
+            Operation::Drop,
+            Operation::MovDn2,
+            Operation::MovUp5,
+            Operation::MovUp2,
+            Operation::Swap,
+            Operation::Drop,
+            Operation::Swap,
+            Operation::MovUp4,
+            Operation::Swap,
+            Operation::Drop,
+            Operation::MovUp4,
+            Operation::MStoreW,
+        ];
+
+        parse_storew(&mut span_ops, &op_store, num_proc_locals)
+            .expect("Failed to parse storew.mem.mask.b1010");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn storew_mem_masked_full_matches_unmasked() {
+        let num_proc_locals = 0;
+
+        // a fully-set mask must degrade to exactly the unmasked storew.mem output
+        let mut span_ops_masked: Vec<Operation> = Vec::new();
+        let op_masked = Token::new("storew.mem.2.mask.b1111", 0);
+        parse_storew(&mut span_ops_masked, &op_masked, num_proc_locals)
+            .expect("Failed to parse storew.mem.2.mask.b1111");
+
+        let mut span_ops_unmasked: Vec<Operation> = Vec::new();
+        let op_unmasked = Token::new("storew.mem.2", 0);
+        parse_storew(&mut span_ops_unmasked, &op_unmasked, num_proc_locals)
+            .expect("Failed to parse storew.mem.2");
+
+        assert_eq!(span_ops_masked, span_ops_unmasked);
+    }
+
+    #[test]
+    fn storew_mem_masked_invalid() {
+        let num_proc_locals = 0;
+
+        // popw.mem discards the new values needed to merge into the existing memory word
+        let op_str = "popw.mem.mask.b1010";
+        let op = Token::new(op_str, 0);
+        let expected = AssemblyError::invalid_op(&op);
+        assert_eq!(get_parsing_error("popw", &op, num_proc_locals), expected);
+
+        // mask value out of range
+        let op_str = "storew.mem.mask.16";
+        let op = Token::new(op_str, 0);
+        let expected = AssemblyError::invalid_param(&op, 3);
+        assert_eq!(get_parsing_error("storew", &op, num_proc_locals), expected);
+
+        // invalid binary mask literal (wrong length)
+        let op_str = "storew.mem.mask.b101";
+        let op = Token::new(op_str, 0);
+        let expected = AssemblyError::invalid_param(&op, 3);
+        assert_eq!(get_parsing_error("storew", &op, num_proc_locals), expected);
+    }
+
+    #[test]
+    fn storew_mem_invalid() {
+        test_parse_mem("storew");
+    }
+
+    // TESTS FOR PROCEDURE-LOCAL ADDRESSING
+    // ============================================================================================
+
+    #[test]
+    fn push_local() {
+        let num_proc_locals = 4;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("push.local.2", 0);
+        let expected = vec![Operation::Push(Felt::new(2)), Operation::FmpAdd, Operation::MLoad];
+
+        parse_push(&mut span_ops, &op, num_proc_locals).expect("Failed to parse push.local.2");
+
+        assert_eq!(&span_ops, &expected);
+
+        // local index 0 folds into a Pad, same as a .mem address of 0
+        let mut span_ops_zero: Vec<Operation> = Vec::new();
+        let op_zero = Token::new("push.local.0", 0);
+        let expected_zero = vec![Operation::Pad, Operation::FmpAdd, Operation::MLoad];
+
+        parse_push(&mut span_ops_zero, &op_zero, num_proc_locals)
+            .expect("Failed to parse push.local.0");
+
+        assert_eq!(&span_ops_zero, &expected_zero);
+    }
+
+    #[test]
+    fn pop_local() {
+        let num_proc_locals = 4;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("pop.local.2", 0);
+        let expected = vec![
+            Operation::Push(Felt::new(2)),
+            Operation::FmpAdd,
+            Operation::MStore,
+            Operation::Drop,
+        ];
+
+        parse_pop(&mut span_ops, &op, num_proc_locals).expect("Failed to parse pop.local.2");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn loadw_local() {
+        let num_proc_locals = 4;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("loadw.local.2", 0);
+        let expected = vec![Operation::Push(Felt::new(2)), Operation::FmpAdd, Operation::MLoadW];
+
+        parse_loadw(&mut span_ops, &op, num_proc_locals).expect("Failed to parse loadw.local.2");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn pushw_local() {
+        let num_proc_locals = 4;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("pushw.local.2", 0);
+        let expected = vec![
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Push(Felt::new(2)),
+            Operation::FmpAdd,
+            Operation::MLoadW,
+        ];
+
+        parse_pushw(&mut span_ops, &op, num_proc_locals).expect("Failed to parse pushw.local.2");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn storew_local() {
+        let num_proc_locals = 4;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("storew.local.2", 0);
+        let expected = vec![Operation::Push(Felt::new(2)), Operation::FmpAdd, Operation::MStoreW];
+
+        parse_storew(&mut span_ops, &op, num_proc_locals)
+            .expect("Failed to parse storew.local.2");
+
+        assert_eq!(&span_ops, &expected);
     }
 
     #[test]
-    fn pop_mem() {
-        let num_proc_locals = 0;
+    fn popw_local() {
+        let num_proc_locals = 4;
 
-        // stores top element of the stack in memory
-        // then removes this element from the top of the stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("popw.local.2", 0);
+        let expected = vec![
+            Operation::Push(Felt::new(2)),
+            Operation::FmpAdd,
+            Operation::MStoreW,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+        ];
 
-        // test pop with memory address on top of the stack
+        parse_popw(&mut span_ops, &op, num_proc_locals).expect("Failed to parse popw.local.2");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn local_index_out_of_bounds() {
+        let num_proc_locals = 4;
+
+        // index equal to the declared local count is out of range (locals are 0-indexed)
+        let op = Token::new("push.local.4", 0);
+        let expected = AssemblyError::local_index_out_of_bounds(&op, 4, num_proc_locals);
+        assert_eq!(
+            get_parsing_error("push", &op, num_proc_locals),
+            expected
+        );
+    }
+
+    #[test]
+    fn local_addr_invalid() {
+        let num_proc_locals = 4;
+
+        // missing local index
+        let op = Token::new("push.local", 0);
+        let expected = AssemblyError::invalid_param(&op, 2);
+        assert_eq!(
+            get_parsing_error("push", &op, num_proc_locals),
+            expected
+        );
+
+        // extra parameter
+        let op = Token::new("push.local.2.3", 0);
+        let expected = AssemblyError::extra_param(&op);
+        assert_eq!(
+            get_parsing_error("push", &op, num_proc_locals),
+            expected
+        );
+
+        // `.local` and `.mask` cannot be combined
+        let op = Token::new("loadw.local.2.mask.b1010", 0);
+        let expected = AssemblyError::invalid_op(&op);
+        assert_eq!(
+            get_parsing_error("loadw", &op, num_proc_locals),
+            expected
+        );
+    }
+
+    // TESTS FOR BASE-PLUS-OFFSET ADDRESSING
+    // ============================================================================================
+
+    #[test]
+    fn push_mem_base() {
+        let num_proc_locals = 0;
+
+        // base address comes from the stack, offset is folded in at assembly time
         let mut span_ops: Vec<Operation> = Vec::new();
-        let op_mem_pop = Token::new("pop.mem", 0);
-        let expected = vec![Operation::MStore, Operation::Drop];
-        parse_pop(&mut span_ops, &op_mem_pop, num_proc_locals).expect("Failed to parse pop.mem");
+        let op = Token::new("push.mem.base.8", 0);
+        let expected = vec![Operation::Push(Felt::new(8)), Operation::Add, Operation::MLoad];
+
+        parse_push(&mut span_ops, &op, num_proc_locals).expect("Failed to parse push.mem.base.8");
+
         assert_eq!(&span_ops, &expected);
 
-        // test pop with memory address provided directly (address 0)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_pop_addr = Token::new("pop.mem.0", 0);
-        let expected_addr = vec![Operation::Pad, Operation::MStore, Operation::Drop];
+        // a zero offset folds into a Pad, same as a zero .mem immediate address
+        let mut span_ops_zero: Vec<Operation> = Vec::new();
+        let op_zero = Token::new("push.mem.base.0", 0);
+        let expected_zero = vec![Operation::Pad, Operation::Add, Operation::MLoad];
 
-        parse_pop(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
-            .expect("Failed to parse pop.mem.0");
+        parse_push(&mut span_ops_zero, &op_zero, num_proc_locals)
+            .expect("Failed to parse push.mem.base.0");
 
-        assert_eq!(&span_ops_addr, &expected_addr);
+        assert_eq!(&span_ops_zero, &expected_zero);
+    }
 
-        // test pop with memory address provided directly (address 2)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_pop_addr = Token::new("pop.mem.2", 0);
-        let expected_addr = vec![
-            Operation::Push(Felt::new(2)),
+    #[test]
+    fn pop_mem_base() {
+        let num_proc_locals = 0;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("pop.mem.base.8", 0);
+        let expected = vec![
+            Operation::Push(Felt::new(8)),
+            Operation::Add,
             Operation::MStore,
             Operation::Drop,
         ];
 
-        parse_pop(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
-            .expect("Failed to parse pop.mem.2");
+        parse_pop(&mut span_ops, &op, num_proc_locals).expect("Failed to parse pop.mem.base.8");
 
-        assert_eq!(&span_ops_addr, &expected_addr);
+        assert_eq!(&span_ops, &expected);
     }
 
     #[test]
-    fn popw_mem() {
+    fn pushw_mem_base() {
         let num_proc_locals = 0;
 
-        // stores the top 4 elements of the stack in memory
-        // then removes those 4 elements from the top of the stack
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("pushw.mem.base.8", 0);
+        let expected = vec![
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::Pad,
+            Operation::MovUp4,
+            Operation::Push(Felt::new(8)),
+            Operation::Add,
+            Operation::MLoadW,
+        ];
+
+        parse_pushw(&mut span_ops, &op, num_proc_locals).expect("Failed to parse pushw.mem.base.8");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn loadw_mem_base() {
+        let num_proc_locals = 0;
 
-        // test pop with memory address on top of the stack
         let mut span_ops: Vec<Operation> = Vec::new();
-        let op_mem_pop = Token::new("popw.mem", 0);
+        let op = Token::new("loadw.mem.base.8", 0);
+        let expected = vec![Operation::Push(Felt::new(8)), Operation::Add, Operation::MLoadW];
+
+        parse_loadw(&mut span_ops, &op, num_proc_locals).expect("Failed to parse loadw.mem.base.8");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn storew_mem_base() {
+        let num_proc_locals = 0;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("storew.mem.base.8", 0);
+        let expected = vec![Operation::Push(Felt::new(8)), Operation::Add, Operation::MStoreW];
+
+        parse_storew(&mut span_ops, &op, num_proc_locals)
+            .expect("Failed to parse storew.mem.base.8");
+
+        assert_eq!(&span_ops, &expected);
+    }
+
+    #[test]
+    fn popw_mem_base() {
+        let num_proc_locals = 0;
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("popw.mem.base.8", 0);
         let expected = vec![
+            Operation::Push(Felt::new(8)),
+            Operation::Add,
             Operation::MStoreW,
             Operation::Drop,
             Operation::Drop,
             Operation::Drop,
             Operation::Drop,
         ];
-        parse_popw(&mut span_ops, &op_mem_pop, num_proc_locals).expect("Failed to parse popw.mem");
+
+        parse_popw(&mut span_ops, &op, num_proc_locals).expect("Failed to parse popw.mem.base.8");
+
         assert_eq!(&span_ops, &expected);
+    }
 
-        // test pop with memory address provided directly (address 0)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_pop_addr = Token::new("popw.mem.0", 0);
-        let expected_addr = vec![
+    #[test]
+    fn base_addr_invalid() {
+        let num_proc_locals = 0;
+
+        // missing offset
+        let op = Token::new("push.mem.base", 0);
+        let expected = AssemblyError::invalid_param(&op, 3);
+        assert_eq!(
+            get_parsing_error("push", &op, num_proc_locals),
+            expected
+        );
+
+        // extra parameter
+        let op = Token::new("push.mem.base.8.9", 0);
+        let expected = AssemblyError::extra_param(&op);
+        assert_eq!(
+            get_parsing_error("push", &op, num_proc_locals),
+            expected
+        );
+
+        // `.base` and `.mask` cannot be combined
+        let op = Token::new("loadw.mem.base.8.mask.b1010", 0);
+        let expected = AssemblyError::invalid_op(&op);
+        assert_eq!(
+            get_parsing_error("loadw", &op, num_proc_locals),
+            expected
+        );
+    }
+
+    // TESTS FOR BULK MEMORY PSEUDO-OPS (MEM.COPY / MEM.FILL)
+    // ============================================================================================
+
+    #[test]
+    fn mem_copy_forward() {
+        // destination starts before the source, so words are copied in ascending address order
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mem.copy.4.0.2", 0);
+        let expected = vec![
+            Operation::Push(Felt::new(4)),
+            Operation::MLoadW,
             Operation::Pad,
             Operation::MStoreW,
             Operation::Drop,
             Operation::Drop,
             Operation::Drop,
             Operation::Drop,
+            Operation::Push(Felt::new(5)),
+            Operation::MLoadW,
+            Operation::Push(Felt::new(1)),
+            Operation::MStoreW,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
         ];
 
-        parse_popw(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
-            .expect("Failed to parse popw.mem.0");
+        super::parse_mem_copy(&mut span_ops, &op).expect("Failed to parse mem.copy.4.0.2");
 
-        assert_eq!(&span_ops_addr, &expected_addr);
+        assert_eq!(&span_ops, &expected);
+    }
 
-        // test pop with memory address provided directly (address 2)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_pop_addr = Token::new("popw.mem.2", 0);
-        let expected_addr = vec![
+    #[test]
+    fn mem_copy_overlapping_copies_backward() {
+        // destination starts after the source and the ranges overlap, so words must be copied in
+        // descending address order to avoid overwriting a source word before it is read
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mem.copy.0.1.2", 0);
+        let expected = vec![
+            Operation::Push(Felt::new(1)),
+            Operation::MLoadW,
             Operation::Push(Felt::new(2)),
             Operation::MStoreW,
             Operation::Drop,
             Operation::Drop,
             Operation::Drop,
             Operation::Drop,
+            Operation::Pad,
+            Operation::MLoadW,
+            Operation::Push(Felt::new(1)),
+            Operation::MStoreW,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
         ];
 
-        parse_popw(&mut span_ops_addr, &op_pop_addr, num_proc_locals)
-            .expect("Failed to parse popw.mem.2");
+        super::parse_mem_copy(&mut span_ops, &op).expect("Failed to parse mem.copy.0.1.2");
 
-        assert_eq!(&span_ops_addr, &expected_addr);
+        assert_eq!(&span_ops, &expected);
     }
 
     #[test]
-    fn popw_mem_invalid() {
-        test_parse_mem("popw");
+    fn mem_copy_invalid() {
+        // missing the copy count
+        let op = Token::new("mem.copy.0.1", 0);
+        let expected = AssemblyError::invalid_param(&op, 4);
+        assert_eq!(super::parse_mem_copy(&mut Vec::new(), &op).unwrap_err(), expected);
+
+        // extra parameter
+        let op = Token::new("mem.copy.0.1.2.3", 0);
+        let expected = AssemblyError::extra_param(&op);
+        assert_eq!(super::parse_mem_copy(&mut Vec::new(), &op).unwrap_err(), expected);
     }
 
-    // TESTS FOR OVERWRITING VALUES ON THE STACK (LOAD)
-    // ============================================================================================
-
     #[test]
-    fn loadw_mem() {
-        let num_proc_locals = 0;
-
-        // reads a word from memory and overwrites the top 4 stack elements
-
-        // test load with memory address on top of stack
+    fn mem_fill() {
         let mut span_ops: Vec<Operation> = Vec::new();
-        let op_push = Token::new("loadw.mem", 0);
-        let expected = vec![Operation::MLoadW];
+        let op = Token::new("mem.fill.2.3", 0);
+        let expected = vec![
+            Operation::Push(Felt::new(2)),
+            Operation::MStoreW,
+            Operation::Push(Felt::new(3)),
+            Operation::MStoreW,
+            Operation::Push(Felt::new(4)),
+            Operation::MStoreW,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+            Operation::Drop,
+        ];
 
-        parse_loadw(&mut span_ops, &op_push, num_proc_locals).expect("Failed to parse loadw.mem");
+        super::parse_mem_fill(&mut span_ops, &op).expect("Failed to parse mem.fill.2.3");
 
         assert_eq!(&span_ops, &expected);
-
-        // test load with memory address provided directly (address 0)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_load_addr = Token::new("loadw.mem.0", 0);
-        let expected_addr = vec![Operation::Pad, Operation::MLoadW];
-
-        parse_loadw(&mut span_ops_addr, &op_load_addr, num_proc_locals)
-            .expect("Failed to parse loadw.mem.0 (address provided by op)");
-
-        assert_eq!(&span_ops_addr, &expected_addr);
-
-        // test load with memory address provided directly (address 2)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_load_addr = Token::new("loadw.mem.2", 0);
-        let expected_addr = vec![Operation::Push(Felt::new(2)), Operation::MLoadW];
-
-        parse_loadw(&mut span_ops_addr, &op_load_addr, num_proc_locals)
-            .expect("Failed to parse loadw.mem.2 (address provided by op)");
-
-        assert_eq!(&span_ops_addr, &expected_addr);
     }
 
     #[test]
-    fn loadw_mem_invalid() {
-        test_parse_mem("loadw");
+    fn mem_fill_invalid() {
+        // missing the fill count
+        let op = Token::new("mem.fill.2", 0);
+        let expected = AssemblyError::invalid_param(&op, 3);
+        assert_eq!(super::parse_mem_fill(&mut Vec::new(), &op).unwrap_err(), expected);
+
+        // extra parameter
+        let op = Token::new("mem.fill.2.3.4", 0);
+        let expected = AssemblyError::extra_param(&op);
+        assert_eq!(super::parse_mem_fill(&mut Vec::new(), &op).unwrap_err(), expected);
     }
 
-    // TESTS FOR SAVING STACK VALUES WITHOUT REMOVING THEM (STORE)
+    // TESTS FOR REGISTRY-BASED DISPATCH (DISPATCH)
     // ============================================================================================
 
     #[test]
-    fn storew_mem() {
+    fn parse_mem_op_dispatches_through_registry() {
         let num_proc_locals = 0;
-        // stores the top 4 elements of the stack in memory
 
-        // test store with memory address on top of the stack
+        // `push.mem` dispatches to the registered `PushMemParser`
         let mut span_ops: Vec<Operation> = Vec::new();
-        let op_store = Token::new("storew.mem", 0);
-        let expected = vec![Operation::MStoreW];
-
-        parse_storew(&mut span_ops, &op_store, num_proc_locals)
-            .expect("Failed to parse storew.mem");
-
-        assert_eq!(&span_ops, &expected);
-
-        // test store with memory address provided directly (address 0)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_store_addr = Token::new("storew.mem.0", 0);
-        let expected_addr = vec![Operation::Pad, Operation::MStoreW];
-
-        parse_storew(&mut span_ops_addr, &op_store_addr, num_proc_locals)
-            .expect("Failed to parse storew.mem.0 with adddress (address provided by op)");
-
-        assert_eq!(&span_ops_addr, &expected_addr);
-
-        // test store with memory address provided directly (address 2)
-        let mut span_ops_addr: Vec<Operation> = Vec::new();
-        let op_store_addr = Token::new("storew.mem.2", 0);
-        let expected_addr = vec![Operation::Push(Felt::new(2)), Operation::MStoreW];
-
-        parse_storew(&mut span_ops_addr, &op_store_addr, num_proc_locals)
-            .expect("Failed to parse storew.mem.2 with adddress (address provided by op)");
+        let op = Token::new("push.mem", 0);
+        super::parse_mem_op(&mut span_ops, &op, num_proc_locals)
+            .expect("Failed to parse push.mem via the registry");
+        assert_eq!(span_ops, vec![Operation::MLoad]);
 
-        assert_eq!(&span_ops_addr, &expected_addr);
-    }
+        // `pop.mem` dispatches to the registered `PopMemParser`
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("pop.mem", 0);
+        super::parse_mem_op(&mut span_ops, &op, num_proc_locals)
+            .expect("Failed to parse pop.mem via the registry");
+        assert_eq!(span_ops, vec![Operation::MStore, Operation::Drop]);
 
-    #[test]
-    fn storew_mem_invalid() {
-        test_parse_mem("storew");
+        // `mem.copy` dispatches to the registered `MemBulkParser`
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("mem.copy.1.2.1", 0);
+        super::parse_mem_op(&mut span_ops, &op, num_proc_locals)
+            .expect("Failed to parse mem.copy via the registry");
+        assert!(!span_ops.is_empty());
+
+        // an unrecognized prefix is rejected by the registry itself
+        let op = Token::new("bogus", 0);
+        let expected = AssemblyError::invalid_op(&op);
+        assert_eq!(
+            super::parse_mem_op(&mut Vec::new(), &op, num_proc_locals).unwrap_err(),
+            expected
+        );
     }
 
     // TEST HELPERS
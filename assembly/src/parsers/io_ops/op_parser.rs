@@ -0,0 +1,152 @@
+use super::{AssemblyError, Box, Operation, Token, Vec};
+
+// OP PARSER
+// ================================================================================================
+
+/// A pluggable parser for a family of related assembly instructions.
+///
+/// Implementations of this trait translate a single [Token] into a sequence of [Operation]s
+/// appended to `span_ops`. A crate can register a parser for a new instruction family (e.g.
+/// domain-specific crypto gadgets or custom memory layouts) with an [OpParserRegistry] without
+/// modifying this module.
+///
+/// The built-in `.mem`-family instructions (see [super::mem_ops]) are themselves registered
+/// implementations: [super::mem_ops::parse_mem_op] builds an [OpParserRegistry], registers a
+/// wrapper [OpParser] per mnemonic, and dispatches through [OpParserRegistry::parse] rather than
+/// matching on `op`'s prefix directly.
+pub trait OpParser {
+    /// Returns the first token part(s) this parser is responsible for, e.g. `&["loadw"]` for an
+    /// instruction invoked as `loadw.mem...`.
+    fn prefixes(&self) -> &[&str];
+
+    /// Parses `op` and appends the resulting operations to `span_ops`.
+    ///
+    /// `num_proc_locals` is the number of locals declared by the enclosing procedure; parsers for
+    /// instructions with no notion of procedure locals (most of them) simply ignore it.
+    ///
+    /// # Errors
+    /// Returns an `AssemblyError` if `op` is not a valid instance of the instruction(s) this
+    /// parser handles.
+    fn parse(
+        &self,
+        span_ops: &mut Vec<Operation>,
+        op: &Token,
+        num_proc_locals: usize,
+    ) -> Result<(), AssemblyError>;
+}
+
+// OP PARSER REGISTRY
+// ================================================================================================
+
+/// A registry of [OpParser] implementations, matching the first part of an instruction token
+/// against each registered parser's `prefixes()`.
+///
+/// This is a general-purpose extension point: register a parser here and call [Self::parse] to
+/// dispatch through it. The built-in `.mem`-family instructions are themselves wired through a
+/// registry of this type (see the note on [OpParser]), rather than being special-cased.
+#[derive(Default)]
+pub struct OpParserRegistry {
+    parsers: Vec<Box<dyn OpParser>>,
+}
+
+impl OpParserRegistry {
+    /// Returns a new, empty [OpParserRegistry].
+    pub fn new() -> Self {
+        Self {
+            parsers: Vec::new(),
+        }
+    }
+
+    /// Registers `parser`, allowing it to override any previously registered parser which shares
+    /// one of its prefixes.
+    pub fn register(&mut self, parser: Box<dyn OpParser>) {
+        self.parsers.push(parser);
+    }
+
+    /// Returns the most recently registered parser whose prefixes contain `prefix`, or `None` if
+    /// no registered parser handles it.
+    pub fn find(&self, prefix: &str) -> Option<&dyn OpParser> {
+        self.parsers
+            .iter()
+            .rev()
+            .find(|parser| parser.prefixes().contains(&prefix))
+            .map(|parser| parser.as_ref())
+    }
+
+    /// Parses `op` using the registered parser matching its first token part.
+    ///
+    /// # Errors
+    /// Returns an `AssemblyError` if no registered parser handles `op`'s first token part, or if
+    /// the matched parser fails to parse `op`.
+    pub fn parse(
+        &self,
+        span_ops: &mut Vec<Operation>,
+        op: &Token,
+        num_proc_locals: usize,
+    ) -> Result<(), AssemblyError> {
+        match self.find(op.parts()[0]) {
+            Some(parser) => parser.parse(span_ops, op, num_proc_locals),
+            None => Err(AssemblyError::invalid_op(op)),
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoopParser;
+
+    impl OpParser for NoopParser {
+        fn prefixes(&self) -> &[&str] {
+            &["noop"]
+        }
+
+        fn parse(
+            &self,
+            span_ops: &mut Vec<Operation>,
+            _op: &Token,
+            _num_proc_locals: usize,
+        ) -> Result<(), AssemblyError> {
+            span_ops.push(Operation::Noop);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn register_and_find() {
+        let mut registry = OpParserRegistry::new();
+        assert!(registry.find("noop").is_none());
+
+        registry.register(Box::new(NoopParser));
+        assert!(registry.find("noop").is_some());
+        assert!(registry.find("push").is_none());
+    }
+
+    #[test]
+    fn register_and_parse() {
+        let mut registry = OpParserRegistry::new();
+        registry.register(Box::new(NoopParser));
+
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("noop", 0);
+        registry
+            .parse(&mut span_ops, &op, 0)
+            .expect("Failed to parse noop via registry");
+
+        assert_eq!(span_ops, vec![Operation::Noop]);
+    }
+
+    #[test]
+    fn parse_unregistered_prefix() {
+        let registry = OpParserRegistry::new();
+        let mut span_ops: Vec<Operation> = Vec::new();
+        let op = Token::new("noop", 0);
+
+        let expected = AssemblyError::invalid_op(&op);
+        assert_eq!(registry.parse(&mut span_ops, &op, 0).unwrap_err(), expected);
+    }
+}
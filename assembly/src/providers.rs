@@ -0,0 +1,26 @@
+//! Pluggable sources of module definitions, consulted when resolving a `use` path in addition to
+//! the built-in standard library -- mirroring how rustc's parser is driven by a configurable
+//! source/`Directory` resolver rather than a fixed location.
+
+use vm_core::utils::string::String;
+
+// MODULE PROVIDER
+// ================================================================================================
+
+/// A source of module definitions that can be registered with an [crate::Assembler] to resolve
+/// `use` paths outside of the standard library -- e.g. a project-local on-disk or in-memory
+/// module library.
+pub trait ModuleProvider {
+    /// Returns the source code of the module at `path`.
+    ///
+    /// # Errors
+    /// Returns [ModuleNotFound] if this provider has no module at `path`.
+    fn get_module_source(&self, path: &str) -> Result<&str, ModuleNotFound>;
+}
+
+/// Returned by a [ModuleProvider] when it has no module at the requested path.
+#[derive(Clone, Debug)]
+pub struct ModuleNotFound {
+    /// The module path that could not be resolved.
+    pub path: String,
+}
@@ -1,6 +1,7 @@
 //! TODO: add docs
 
 use super::{Felt, FieldElement, Word, HASHER_AUX_TRACE_OFFSET};
+use crate::utils::collections::{BTreeMap, Vec};
 use core::ops::Range;
 use crypto::{ElementHasher, Hasher as HashFn};
 
@@ -137,6 +138,453 @@ pub fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
     Hasher::apply_permutation(state)
 }
 
+// MIDEN HASHER
+// ================================================================================================
+
+/// Abstraction over a Miden-compatible hash function.
+///
+/// This captures the shape of the sponge state (rate/capacity widths, round count) and the
+/// operations the hasher chiplet and the host-side Merkle utilities in this module rely on. It is
+/// implemented for [Hasher] (Rescue Prime) below, which remains the default used by the
+/// non-generic pass-through functions above; an alternative arithmetization-friendly permutation
+/// can be plugged in by providing a new impl and using the `_with` function variants below
+/// instead.
+pub trait MidenHasher {
+    /// Output type of this hash function.
+    type Digest: Copy + Eq;
+
+    /// Number of field elements needed to represent the sponge state.
+    const STATE_WIDTH: usize;
+
+    /// Number of field elements in the rate portion of the sponge state.
+    const RATE_LEN: usize;
+
+    /// Number of rounds needed to complete a single permutation.
+    const NUM_ROUNDS: usize;
+
+    /// Returns a hash of two digests. This method is intended for use in construction of Merkle
+    /// trees.
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest;
+
+    /// Returns a hash of the provided list of field elements.
+    fn hash_elements(elements: &[Felt]) -> Self::Digest;
+
+    /// Applies a single round of this hasher's permutation to the provided state.
+    ///
+    /// The state must be [Self::STATE_WIDTH] elements long, and `round` must be between 0 and
+    /// `Self::NUM_ROUNDS - 1` (both inclusive).
+    fn apply_round(state: &mut [Felt], round: usize);
+
+    /// Applies this hasher's full permutation to the provided state, which must be
+    /// [Self::STATE_WIDTH] elements long.
+    fn apply_permutation(state: &mut [Felt]);
+
+    /// Initializes hasher state with the first [Self::RATE_LEN] elements to be absorbed and the
+    /// specified total number of elements to be absorbed. Returns a vector of length
+    /// [Self::STATE_WIDTH].
+    fn init_state(init_values: &[Felt], num_elements: usize) -> Vec<Felt>;
+
+    /// Absorbs the specified values (of length [Self::RATE_LEN]) into the provided state (of
+    /// length [Self::STATE_WIDTH]) by adding them to the corresponding elements in the rate
+    /// portion of the state.
+    fn absorb_into_state(state: &mut [Felt], values: &[Felt]);
+
+    /// Returns the elements representing the digest portion of the provided state, which must be
+    /// [Self::STATE_WIDTH] elements long.
+    fn get_digest(state: &[Felt]) -> [Felt; DIGEST_LEN];
+}
+
+impl MidenHasher for Hasher {
+    type Digest = Digest;
+
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE_LEN: usize = RATE_LEN;
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        merge(values)
+    }
+
+    fn hash_elements(elements: &[Felt]) -> Self::Digest {
+        hash_elements(elements)
+    }
+
+    fn apply_round(state: &mut [Felt], round: usize) {
+        let state: &mut [Felt; STATE_WIDTH] =
+            state.try_into().expect("state must be STATE_WIDTH elements long");
+        apply_round(state, round)
+    }
+
+    fn apply_permutation(state: &mut [Felt]) {
+        let state: &mut [Felt; STATE_WIDTH] =
+            state.try_into().expect("state must be STATE_WIDTH elements long");
+        apply_permutation(state)
+    }
+
+    fn init_state(init_values: &[Felt], num_elements: usize) -> Vec<Felt> {
+        let init_values: &[Felt; RATE_LEN] =
+            init_values.try_into().expect("init_values must be RATE_LEN elements long");
+        self::init_state(init_values, num_elements).to_vec()
+    }
+
+    fn absorb_into_state(state: &mut [Felt], values: &[Felt]) {
+        let state: &mut [Felt; STATE_WIDTH] =
+            state.try_into().expect("state must be STATE_WIDTH elements long");
+        let values: &[Felt; RATE_LEN] =
+            values.try_into().expect("values must be RATE_LEN elements long");
+        self::absorb_into_state(state, values)
+    }
+
+    fn get_digest(state: &[Felt]) -> [Felt; DIGEST_LEN] {
+        let state: &[Felt; STATE_WIDTH] =
+            state.try_into().expect("state must be STATE_WIDTH elements long");
+        self::get_digest(state)
+    }
+}
+
+/// Returns a hash of two digests using the specified [MidenHasher] implementation. Generic
+/// counterpart to [merge].
+#[inline(always)]
+pub fn merge_with<H: MidenHasher>(values: &[H::Digest; 2]) -> H::Digest {
+    H::merge(values)
+}
+
+/// Returns a hash of the provided list of field elements using the specified [MidenHasher]
+/// implementation. Generic counterpart to [hash_elements].
+#[inline(always)]
+pub fn hash_elements_with<H: MidenHasher>(elements: &[Felt]) -> H::Digest {
+    H::hash_elements(elements)
+}
+
+/// Applies a single round of the specified [MidenHasher] implementation's permutation to the
+/// provided state. Generic counterpart to [apply_round].
+#[inline(always)]
+pub fn apply_round_with<H: MidenHasher>(state: &mut [Felt], round: usize) {
+    H::apply_round(state, round)
+}
+
+/// Applies the specified [MidenHasher] implementation's full permutation to the provided state.
+/// Generic counterpart to [apply_permutation].
+#[inline(always)]
+pub fn apply_permutation_with<H: MidenHasher>(state: &mut [Felt]) {
+    H::apply_permutation(state)
+}
+
+/// Initializes hasher state using the specified [MidenHasher] implementation. Generic counterpart
+/// to [init_state].
+#[inline(always)]
+pub fn init_state_with<H: MidenHasher>(init_values: &[Felt], num_elements: usize) -> Vec<Felt> {
+    H::init_state(init_values, num_elements)
+}
+
+/// Absorbs values into the provided state using the specified [MidenHasher] implementation.
+/// Generic counterpart to [absorb_into_state].
+#[inline(always)]
+pub fn absorb_into_state_with<H: MidenHasher>(state: &mut [Felt], values: &[Felt]) {
+    H::absorb_into_state(state, values)
+}
+
+/// Returns the digest portion of the provided state using the specified [MidenHasher]
+/// implementation. Generic counterpart to [get_digest].
+#[inline(always)]
+pub fn get_digest_with<H: MidenHasher>(state: &[Felt]) -> [Felt; DIGEST_LEN] {
+    H::get_digest(state)
+}
+
+// BYTE HASHING
+// ================================================================================================
+
+/// Number of bytes packed into a single field element by [bytes_to_field_elements]. Each chunk is
+/// interpreted as a little-endian integer, which is guaranteed to be less than 2^56 -- well below
+/// the Goldilocks modulus p = 2^64 - 2^32 + 1 -- so the packing is injective.
+const BYTES_PER_ELEMENT: usize = 7;
+
+/// Converts a byte slice into a sequence of field elements suitable for hashing with
+/// [hash_elements].
+///
+/// The input is first terminated with a single `0x01` byte, followed by as many `0x00` bytes as
+/// needed to bring its length to a multiple of 7; this keeps the original length recoverable and
+/// ensures distinct inputs (including ones that only differ by trailing zero bytes) never collide.
+/// The padded bytes are then split into 7-byte little-endian chunks, each of which maps to a
+/// distinct field element.
+pub fn bytes_to_field_elements(bytes: &[u8]) -> Vec<Felt> {
+    let mut padded = bytes.to_vec();
+    padded.push(0x01);
+    while padded.len() % BYTES_PER_ELEMENT != 0 {
+        padded.push(0x00);
+    }
+
+    padded
+        .chunks_exact(BYTES_PER_ELEMENT)
+        .map(|chunk| {
+            let mut buf = [0u8; 8];
+            buf[..BYTES_PER_ELEMENT].copy_from_slice(chunk);
+            Felt::new(u64::from_le_bytes(buf))
+        })
+        .collect()
+}
+
+/// Returns a hash of the provided byte slice.
+///
+/// The bytes are first packed into field elements via [bytes_to_field_elements], then absorbed
+/// with [hash_elements].
+#[inline(always)]
+pub fn hash_bytes(bytes: &[u8]) -> Digest {
+    hash_elements(&bytes_to_field_elements(bytes))
+}
+
+// MERKLE TREE
+// ================================================================================================
+
+/// A minimal, fully-materialized Merkle tree over [Digest] leaves, built using [merge] to combine
+/// sibling nodes. The number of leaves must be a power of two.
+///
+/// This is a host-side (off-circuit) counterpart to the VM's Merkle-path-verification and
+/// root-update chiplet operations (see [MP_VERIFY], [MR_UPDATE_OLD], [MR_UPDATE_NEW]): the leaf,
+/// path, and root values produced and consumed by [MerkleTree::open], [verify], and
+/// [MerkleTree::update] are exactly the ones those operations expect.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    nodes: Vec<Digest>,
+    depth: usize,
+}
+
+impl MerkleTree {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [MerkleTree] instantiated from the provided leaves.
+    ///
+    /// The tree is stored as a single flat vector: node 1 is the root, the children of node `i`
+    /// are nodes `2i` and `2i + 1`, and the leaves occupy the second half of the vector.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of two greater than 1.
+    pub fn new(leaves: Vec<Digest>) -> Self {
+        let num_leaves = leaves.len();
+        assert!(
+            num_leaves.is_power_of_two() && num_leaves > 1,
+            "number of leaves must be a power of two greater than 1"
+        );
+        let depth = num_leaves.trailing_zeros() as usize;
+
+        let mut nodes = Vec::with_capacity(2 * num_leaves);
+        nodes.resize(num_leaves, Digest::default());
+        nodes.extend(leaves);
+
+        for i in (1..num_leaves).rev() {
+            nodes[i] = merge(&[nodes[2 * i], nodes[2 * i + 1]]);
+        }
+
+        Self { nodes, depth }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of this Merkle tree.
+    pub fn root(&self) -> Digest {
+        self.nodes[1]
+    }
+
+    /// Returns the depth of this Merkle tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the leaf at the specified index together with its authentication path, ordered
+    /// from the bottom (the leaf's sibling) to the top (the sibling of the root's child).
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn open(&self, index: usize) -> (Digest, Vec<Digest>) {
+        let num_leaves = self.nodes.len() / 2;
+        assert!(index < num_leaves, "leaf index out of bounds");
+
+        let leaf = self.nodes[num_leaves + index];
+        let mut path = Vec::with_capacity(self.depth);
+        let mut pos = num_leaves + index;
+        while pos > 1 {
+            path.push(self.nodes[pos ^ 1]);
+            pos /= 2;
+        }
+
+        (leaf, path)
+    }
+
+    /// Updates the leaf at the specified index to `new_leaf`, recomputes only the nodes on its
+    /// path to the root, and returns the new root.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn update(&mut self, index: usize, new_leaf: Digest) -> Digest {
+        let num_leaves = self.nodes.len() / 2;
+        assert!(index < num_leaves, "leaf index out of bounds");
+
+        let mut pos = num_leaves + index;
+        self.nodes[pos] = new_leaf;
+        while pos > 1 {
+            pos /= 2;
+            self.nodes[pos] = merge(&[self.nodes[2 * pos], self.nodes[2 * pos + 1]]);
+        }
+
+        self.root()
+    }
+}
+
+/// Verifies that `leaf` at the given `index` authenticates to `root` via `path`, re-folding the
+/// path bottom-up with [merge] and using the low bit of the (right-shifted) index at each level to
+/// pick left/right ordering, matching [MerkleTree::open]'s path ordering.
+pub fn verify(root: Digest, index: usize, leaf: Digest, path: &[Digest]) -> bool {
+    let mut pos = index;
+    let mut acc = leaf;
+    for &sibling in path {
+        acc = if pos & 1 == 0 {
+            merge(&[acc, sibling])
+        } else {
+            merge(&[sibling, acc])
+        };
+        pos >>= 1;
+    }
+
+    acc == root
+}
+
+// SPARSE MERKLE TREE
+// ================================================================================================
+
+/// Returns the chain of "empty subtree" digests for a sparse Merkle tree of the given `depth`,
+/// indexed from the leaf level (`empty_hashes(depth)[0]`, the digest of an all-ZERO leaf) up to
+/// the root level (`empty_hashes(depth)[depth]`). Level `l` is `merge([empty_hashes[l - 1]; 2])`:
+/// the hash of two empty level `l - 1` subtrees is, by definition, the empty level `l` subtree.
+///
+/// This is the table `smtree.get`/`smtree.set` rely on to substitute for any subtree that was
+/// never written, so a fixed-depth key-value map can be committed without materializing every
+/// leaf.
+pub fn empty_hashes(depth: usize) -> Vec<Digest> {
+    let mut hashes = Vec::with_capacity(depth + 1);
+    hashes.push(Digest::default());
+    for level in 1..=depth {
+        let prev = hashes[level - 1];
+        hashes.push(merge(&[prev, prev]));
+    }
+    hashes
+}
+
+/// A sparse Merkle tree of a fixed `depth` (at most 64), where any leaf that was never written
+/// holds the default (all-ZERO) value. Only explicitly-set leaves are stored; every other subtree
+/// collapses to the precomputed [empty_hashes] entry for its level, so a tree with a large depth
+/// can be committed and opened without materializing every leaf.
+///
+/// This is the host-side counterpart to `smtree.get`/`smtree.set`: the sibling nodes
+/// [SparseMerkleTree::open] returns -- real ones for written subtrees, empty-hash substitutions
+/// everywhere else -- are exactly what those ops expect to find on the advice tape when walking a
+/// path through the tree.
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree {
+    leaves: BTreeMap<u64, Digest>,
+    empty_hashes: Vec<Digest>,
+    depth: usize,
+}
+
+impl SparseMerkleTree {
+    /// Returns a new, empty [SparseMerkleTree] of the given `depth` (every leaf starts at the
+    /// default ZERO value).
+    ///
+    /// # Panics
+    /// Panics if `depth` is greater than 64 (leaf indices are addressed with a `u64`).
+    pub fn new(depth: usize) -> Self {
+        assert!(depth <= 64, "sparse Merkle tree depth must be at most 64");
+        Self {
+            leaves: BTreeMap::new(),
+            empty_hashes: empty_hashes(depth),
+            depth,
+        }
+    }
+
+    /// Returns the root of this sparse Merkle tree.
+    pub fn root(&self) -> Digest {
+        self.node_at(self.depth, 0)
+    }
+
+    /// Returns the depth of this sparse Merkle tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the value at `index`, or the default ZERO value if it was never set.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: u64) -> Digest {
+        self.assert_in_bounds(index);
+        self.leaves.get(&index).copied().unwrap_or(self.empty_hashes[0])
+    }
+
+    /// Sets the value at `index` to `new_leaf` (setting it back to the default ZERO value removes
+    /// it from the underlying sparse storage) and returns the new root.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn update(&mut self, index: u64, new_leaf: Digest) -> Digest {
+        self.assert_in_bounds(index);
+
+        if new_leaf == self.empty_hashes[0] {
+            self.leaves.remove(&index);
+        } else {
+            self.leaves.insert(index, new_leaf);
+        }
+
+        self.root()
+    }
+
+    /// Returns the leaf at `index` together with its authentication path, ordered from the bottom
+    /// (the leaf's sibling) to the top (the sibling of the root's child). Any sibling whose
+    /// subtree was never written is the precomputed empty-node hash for its level, rather than a
+    /// freshly recomputed one.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn open(&self, index: u64) -> (Digest, Vec<Digest>) {
+        self.assert_in_bounds(index);
+
+        let leaf = self.get(index);
+        let mut path = Vec::with_capacity(self.depth);
+        for level in 0..self.depth {
+            let sibling_index = (index >> level) ^ 1;
+            path.push(self.node_at(level, sibling_index));
+        }
+
+        (leaf, path)
+    }
+
+    fn assert_in_bounds(&self, index: u64) {
+        assert!((index as u128) < 1u128 << self.depth, "leaf index out of bounds");
+    }
+
+    /// Returns the digest of the node at the given `level` (0 = leaves, [Self::depth] = root) and
+    /// `index` within that level, substituting the precomputed empty-subtree hash for any subtree
+    /// that was never written rather than recursing into it.
+    fn node_at(&self, level: usize, index: u64) -> Digest {
+        if level == 0 {
+            return self.get(index);
+        }
+
+        // subtree bounds are computed in u128, since the root of a depth-64 tree spans 2^64 leaves
+        let num_leaves_below = 1u128 << level;
+        let start = index as u128 * num_leaves_below;
+        let end = start + num_leaves_below;
+        let first_below = self.leaves.range(start as u64..).next();
+        if first_below.map_or(true, |(&i, _)| i as u128 >= end) {
+            return self.empty_hashes[level];
+        }
+
+        let left = self.node_at(level - 1, index * 2);
+        let right = self.node_at(level - 1, index * 2 + 1);
+        merge(&[left, right])
+    }
+}
+
 // HASHER STATE MUTATORS
 // ================================================================================================
 
@@ -24,3 +24,19 @@ pub enum AdviceSetError {
 pub enum LibraryError {
     ModuleNotFound(String),
 }
+
+#[derive(Clone, Debug)]
+pub enum CodecError {
+    InvalidOpcode(u8),
+    UnexpectedEof,
+}
+
+#[derive(Clone, Debug)]
+pub enum Bech32Error {
+    MixedCase,
+    InvalidChar(char),
+    InvalidChecksum,
+    InvalidHrp(String),
+    TooShort,
+    MissingSeparator,
+}
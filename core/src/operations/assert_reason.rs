@@ -0,0 +1,65 @@
+//! A small registry of well-known [Operation::Assert] and [Operation::U32assert2] failure codes,
+//! plus an escape hatch for user-defined ones.
+
+/// Maps an [Operation::Assert](super::Operation::Assert) or
+/// [Operation::U32assert2](super::Operation::U32assert2) error code to a human-readable message.
+///
+/// Codes below [AssertReason::USER_CODE_START] are reserved for the well-known reasons below;
+/// everything at or above it is free for programs to assign their own meaning to.
+///
+/// Turning one of these codes into an actionable diagnostic at the point where an `assert`
+/// actually fails during execution is the job of whatever processor-side code drives `Assert`/
+/// `U32assert2` (e.g. an `ExecutionError::FailedAssertion(AssertReason)` variant constructed by
+/// that op's handler); that execution-side plumbing lives outside `core` and isn't part of this
+/// crate.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AssertReason {
+    /// A boolean condition expected to hold (e.g. an `if` guard) was false.
+    UnmatchedCondition,
+    /// A value expected to fit in 32 bits (e.g. checked by `U32assert2`) did not.
+    RangeCheckFailed,
+    /// A Merkle path failed to authenticate against the expected root.
+    MerklePathMismatch,
+    /// A code outside the well-known registry; the program that raised it defines its meaning.
+    Custom(u32),
+}
+
+impl AssertReason {
+    /// The first error code available for user-defined assertions; codes below this value are
+    /// reserved for the well-known reasons in this registry.
+    pub const USER_CODE_START: u32 = 1 << 16;
+
+    /// Returns a human-readable message describing this assertion failure reason.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::UnmatchedCondition => "a boolean condition expected to hold was false",
+            Self::RangeCheckFailed => "a value expected to fit in 32 bits did not",
+            Self::MerklePathMismatch => {
+                "a Merkle path did not authenticate against the expected root"
+            }
+            Self::Custom(_) => "a user-defined assertion failed",
+        }
+    }
+}
+
+impl From<u32> for AssertReason {
+    fn from(code: u32) -> Self {
+        match code {
+            0 => Self::UnmatchedCondition,
+            1 => Self::RangeCheckFailed,
+            2 => Self::MerklePathMismatch,
+            code => Self::Custom(code),
+        }
+    }
+}
+
+impl From<AssertReason> for u32 {
+    fn from(reason: AssertReason) -> Self {
+        match reason {
+            AssertReason::UnmatchedCondition => 0,
+            AssertReason::RangeCheckFailed => 1,
+            AssertReason::MerklePathMismatch => 2,
+            AssertReason::Custom(code) => code,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! A binary codec for [Operation], used to serialize compiled programs to a portable bytecode
+//! format and load them back without re-assembly.
+//!
+//! Each operation is encoded as its opcode byte (see [Operation::op_code]) followed by the
+//! trailing payload required to reconstruct any immediate/flag it carries: 8 little-endian bytes
+//! for a `Felt` immediate ([Operation::Push] and the `*Imm`/`IncrBy` family), 4 little-endian
+//! bytes for an [Operation::Assert] or [Operation::U32assert2] error code, a single flag byte for
+//! [Operation::MrUpdate], a single count/exponent/window-width byte for [Operation::InvN], the
+//! [Operation::Ntt]/[Operation::Intt] pair, and [Operation::ExpAccW], or a single secondary-opcode
+//! byte for [Operation::Escape]. All other operations have no payload.
+
+use super::Operation;
+use crate::errors::CodecError;
+use crate::utils::collections::Vec;
+use crate::{Felt, StarkField};
+
+impl Operation {
+    /// Appends the binary encoding of this operation to `target`.
+    pub fn encode(&self, target: &mut Vec<u8>) {
+        target.push(self.op_code());
+
+        match self {
+            Self::Push(imm)
+            | Self::AddImm(imm)
+            | Self::MulImm(imm)
+            | Self::EqImm(imm)
+            | Self::IncrBy(imm) => target.extend_from_slice(&imm.as_int().to_le_bytes()),
+            Self::Assert(code) | Self::U32assert2(code) => {
+                target.extend_from_slice(&code.to_le_bytes())
+            }
+            Self::MrUpdate(copy) => target.push(*copy as u8),
+            Self::InvN(n) | Self::Ntt(n) | Self::Intt(n) | Self::ExpAccW(n) => target.push(*n),
+            Self::Escape(secondary_op) => target.push(*secondary_op),
+            _ => {}
+        }
+    }
+
+    /// Decodes a single [Operation] from the front of `bytes`, advancing `bytes` past the opcode
+    /// and any payload it consumed.
+    ///
+    /// # Errors
+    /// Returns an error if `bytes` is empty, the leading byte is not a valid opcode, or the
+    /// payload required by the decoded opcode (e.g. the 8-byte immediate of a `push`) is
+    /// truncated.
+    pub fn decode(bytes: &mut &[u8]) -> Result<Self, CodecError> {
+        let (&op_code, rest) = bytes.split_first().ok_or(CodecError::UnexpectedEof)?;
+        *bytes = rest;
+
+        let op = match op_code {
+            0 => Self::Noop,
+            1 => Self::Assert(read_u32(bytes)?),
+
+            2 => Self::FmpAdd,
+            3 => Self::FmpUpdate,
+
+            4 => Self::Push(read_felt(bytes)?),
+
+            0b0100_1001 => Self::Eq,
+            38 => Self::EqImm(read_felt(bytes)?),
+            5 => Self::Eqz,
+            87 => Self::Lt,
+            88 => Self::Lte,
+            89 => Self::Gt,
+            90 => Self::Gte,
+
+            0b0100_1000 => Self::Add,
+            6 => Self::AddImm(read_felt(bytes)?),
+            7 => Self::Neg,
+            0b0100_1010 => Self::Mul,
+            10 => Self::MulImm(read_felt(bytes)?),
+            8 => Self::Inv,
+            48 => Self::InvN(read_u8(bytes)?),
+            47 => Self::ExtAdd,
+            49 => Self::ExtMul,
+            86 => Self::ExtInv,
+            9 => Self::Incr,
+            39 => Self::IncrBy(read_felt(bytes)?),
+            0b0100_1011 => Self::And,
+            0b0100_1100 => Self::Or,
+            11 => Self::Not,
+
+            12 => Self::Pad,
+            13 => Self::Drop,
+
+            14 => Self::Dup0,
+            15 => Self::Dup1,
+            16 => Self::Dup2,
+            17 => Self::Dup3,
+            18 => Self::Dup4,
+            19 => Self::Dup5,
+            20 => Self::Dup6,
+            21 => Self::Dup7,
+            22 => Self::Dup9,
+            23 => Self::Dup11,
+            24 => Self::Dup13,
+            25 => Self::Dup15,
+
+            26 => Self::Swap,
+            27 => Self::SwapW,
+            28 => Self::SwapW2,
+            29 => Self::SwapW3,
+            30 => Self::SwapDW,
+
+            31 => Self::MovUp2,
+            32 => Self::MovUp3,
+            33 => Self::MovUp4,
+            34 => Self::MovUp5,
+            35 => Self::MovUp6,
+            36 => Self::MovUp7,
+            37 => Self::MovUp8,
+
+            40 => Self::MovDn2,
+            41 => Self::MovDn3,
+            42 => Self::MovDn4,
+            43 => Self::MovDn5,
+            44 => Self::MovDn6,
+            45 => Self::MovDn7,
+            46 => Self::MovDn8,
+
+            50 => Self::CSwap,
+            51 => Self::CSwapW,
+
+            0b0100_0000 => Self::U32add,
+            0b0100_0001 => Self::U32sub,
+            0b0100_0010 => Self::U32mul,
+            0b0100_0011 => Self::U32div,
+            0b0100_0100 => Self::U32add3,
+            0b0100_0101 => Self::U32madd,
+            0b0100_0110 => Self::U32split,
+            0b0100_0111 => Self::U32assert2(read_u32(bytes)?),
+
+            0b0100_1101 => Self::U32and,
+            0b0100_1110 => Self::U32or,
+            0b0100_1111 => Self::U32xor,
+
+            52 => Self::MLoadW,
+            53 => Self::MStoreW,
+
+            54 => Self::Read,
+            55 => Self::ReadW,
+
+            56 => Self::SDepth,
+            91 => Self::Ntt(read_u8(bytes)?),
+            92 => Self::Intt(read_u8(bytes)?),
+            93 => Self::ExpAccW(read_u8(bytes)?),
+
+            57 => Self::RpPerm,
+            58 => Self::MpVerify,
+            59 => Self::MrUpdate(read_flag(bytes)?),
+
+            60 => Self::End,
+            61 => Self::Join,
+            62 => Self::Split,
+            63 => Self::Loop,
+            80 => Self::Repeat,
+            81 => Self::Respan,
+            82 => Self::Span,
+            83 => Self::Halt,
+            84 => Self::MLoad,
+            85 => Self::MStore,
+
+            Self::ESCAPE_OPCODE => Self::Escape(read_u8(bytes)?),
+
+            _ => return Err(CodecError::InvalidOpcode(op_code)),
+        };
+
+        Ok(op)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Reads a little-endian-encoded field element from the front of `bytes`, advancing `bytes` past
+/// it.
+fn read_felt(bytes: &mut &[u8]) -> Result<Felt, CodecError> {
+    if bytes.len() < 8 {
+        return Err(CodecError::UnexpectedEof);
+    }
+    let (value, rest) = bytes.split_at(8);
+    *bytes = rest;
+
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(value);
+    Ok(Felt::new(u64::from_le_bytes(buf)))
+}
+
+/// Reads a little-endian-encoded `u32` from the front of `bytes`, advancing `bytes` past it.
+fn read_u32(bytes: &mut &[u8]) -> Result<u32, CodecError> {
+    if bytes.len() < 4 {
+        return Err(CodecError::UnexpectedEof);
+    }
+    let (value, rest) = bytes.split_at(4);
+    *bytes = rest;
+
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(value);
+    Ok(u32::from_le_bytes(buf))
+}
+
+/// Reads a single byte from the front of `bytes`, advancing `bytes` past it.
+fn read_u8(bytes: &mut &[u8]) -> Result<u8, CodecError> {
+    let (&byte, rest) = bytes.split_first().ok_or(CodecError::UnexpectedEof)?;
+    *bytes = rest;
+    Ok(byte)
+}
+
+/// Reads a single boolean flag byte from the front of `bytes`, advancing `bytes` past it.
+fn read_flag(bytes: &mut &[u8]) -> Result<bool, CodecError> {
+    let (&flag, rest) = bytes.split_first().ok_or(CodecError::UnexpectedEof)?;
+    *bytes = rest;
+    Ok(flag != 0)
+}
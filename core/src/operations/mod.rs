@@ -5,6 +5,11 @@ pub use decorators::{
     AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList, ProcMarker,
 };
 
+mod codec;
+
+mod assert_reason;
+pub use assert_reason::AssertReason;
+
 // OPERATIONS
 // ================================================================================================
 
@@ -15,8 +20,9 @@ pub enum Operation {
     /// Advances cycle counter, but does not change the state of user stack.
     Noop,
 
-    /// Pops the stack; if the popped value is not 1, execution fails.
-    Assert,
+    /// Pops the stack; if the popped value is not 1, execution fails with the attached error
+    /// code. See [AssertReason] for the registry of well-known codes.
+    Assert(u32),
 
     /// Pops an element off the stack, adds the current value of the `fmp` register to it, and
     /// pushes the result back onto the stack.
@@ -56,19 +62,62 @@ pub enum Operation {
     /// Pops two elements off the stack, adds them, and pushes the result back onto the stack.
     Add,
 
+    /// Pops an element off the stack, adds the embedded immediate to it, and pushes the result
+    /// back onto the stack. Equivalent to `Push(imm)` followed by `Add`, but in a single cycle
+    /// and without spending a stack slot on the immediate.
+    AddImm(Felt),
+
     /// Pops an element off the stack, negates it, and pushes the result back onto the stack.
     Neg,
 
     /// Pops two elements off the stack, multiplies them, and pushes the result back onto the stack.
     Mul,
 
+    /// Pops an element off the stack, multiplies it by the embedded immediate, and pushes the
+    /// result back onto the stack. Equivalent to `Push(imm)` followed by `Mul`, but in a single
+    /// cycle and without spending a stack slot on the immediate.
+    MulImm(Felt),
+
     /// Pops an element off the stack, computes its multiplicative inverse, and pushes the result
     /// back onto the stack.
     Inv,
 
+    /// Inverts the top `n` stack elements in place, where `n` is the embedded immediate.
+    ///
+    /// This uses Montgomery's batch-inversion trick (running prefix products, a single
+    /// inversion of their product, then a reverse pass recovering each element's inverse) so the
+    /// whole batch costs one inversion plus `~3(n-1)` multiplications rather than `n` separate
+    /// inversions. Fails if any of the `n` elements is ZERO.
+    InvN(u8),
+
     /// Pops an element off the stack, adds 1 to it, and pushes the result back onto the stack.
     Incr,
 
+    /// Pops an element off the stack, adds the embedded immediate to it, and pushes the result
+    /// back onto the stack. Equivalent to [Self::AddImm], provided as the natural generalization
+    /// of [Self::Incr] to an arbitrary constant.
+    IncrBy(Felt),
+
+    // ----- degree-2 extension field operations ---------------------------------------------------
+    // These treat a pair of adjacent stack elements (a0, a1) as the extension field element
+    // a0 + a1*x of the degree-2 extension defined by the irreducible x^2 = NONRESIDUE. They exist
+    // because STARK/FRI soundness over the Goldilocks base field generally requires working in
+    // this extension, and emulating it in assembly costs far more than a native operation.
+    /// Pops two extension field elements (4 base elements) off the stack, adds them, and pushes
+    /// the result (2 base elements) back onto the stack.
+    ExtAdd,
+
+    /// Pops two extension field elements (4 base elements) off the stack, multiplies them, and
+    /// pushes the result (2 base elements) back onto the stack.
+    ExtMul,
+
+    /// Pops an extension field element (2 base elements) off the stack, computes its
+    /// multiplicative inverse, and pushes the result (2 base elements) back onto the stack.
+    ///
+    /// # Errors (processor)
+    /// Fails if the element's norm is ZERO, i.e. if the element itself is ZERO.
+    ExtInv,
+
     /// Pops two elements off the stack, multiplies them, and pushes the result back onto the stack.
     ///
     /// If either of the elements is greater than 1, execution fails. This operation is equivalent
@@ -91,10 +140,36 @@ pub enum Operation {
     /// onto the stack, otherwise pushes 0 onto the stack.
     Eq,
 
+    /// Pops an element off the stack and compares it to the embedded immediate. If they are
+    /// equal, pushes 1 onto the stack, otherwise pushes 0 onto the stack. Equivalent to
+    /// `Push(imm)` followed by `Eq`, but in a single cycle and without spending a stack slot on
+    /// the immediate.
+    EqImm(Felt),
+
     /// Pops an element off the stack and compares it to 0. If the element is 0, pushes 1 onto
     /// the stack, otherwise pushes 0 onto the stack.
     Eqz,
 
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is strictly less than the element on top, pushes 1 onto the stack,
+    /// otherwise pushes 0 onto the stack.
+    Lt,
+
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is less than or equal to the element on top, pushes 1 onto the stack,
+    /// otherwise pushes 0 onto the stack.
+    Lte,
+
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is strictly greater than the element on top, pushes 1 onto the stack,
+    /// otherwise pushes 0 onto the stack.
+    Gt,
+
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is greater than or equal to the element on top, pushes 1 onto the
+    /// stack, otherwise pushes 0 onto the stack.
+    Gte,
+
     // ----- u32 operations -----------------------------------------------------------------------
     /// Pops an element off the stack, splits it into upper and lower 32-bit values, and pushes
     /// these values back onto the stack.
@@ -108,8 +183,9 @@ pub enum Operation {
     U32add,
 
     /// Pops two elements off the stack and checks if each of them represents a 32-bit value.
-    /// If both of them are, they are pushed back onto the stack, otherwise an error is returned.
-    U32assert2,
+    /// If both of them are, they are pushed back onto the stack; otherwise execution fails with
+    /// the attached error code. See [AssertReason] for the registry of well-known codes.
+    U32assert2(u32),
 
     /// Pops three elements off the stack, adds them together, and splits the result into upper
     /// and lower 32-bit values. Then pushes the result back onto the stack.
@@ -307,6 +383,45 @@ pub enum Operation {
     /// Pushes the current depth of the stack onto the stack.
     SDepth,
 
+    // ----- polynomial operations -----------------------------------------------------------------
+    /// Pops an element off the stack, interprets it as the base address of a vector of `2^k`
+    /// field elements (where `k` is the embedded immediate), and applies an in-place forward
+    /// Number Theoretic Transform to it using the order-`2^k` root of unity derived from
+    /// Goldilocks' 2^32-th root of unity.
+    ///
+    /// This, combined with [Self::Intt] and pointwise multiplication, lets programs multiply
+    /// polynomials in `O(n log n)` rather than emulating schoolbook `O(n^2)` multiplication in
+    /// assembly.
+    ///
+    /// # Errors (processor)
+    /// Fails if `k` is greater than the two-adicity of the field (32).
+    Ntt(u8),
+
+    /// Pops an element off the stack, interprets it as the base address of a vector of `2^k`
+    /// field elements (where `k` is the embedded immediate), and applies an in-place inverse
+    /// Number Theoretic Transform to it: the same butterfly network as [Self::Ntt] run with the
+    /// inverse root of unity, followed by scaling every element by `(2^k)^{-1}`.
+    ///
+    /// # Errors (processor)
+    /// Fails if `k` is greater than the two-adicity of the field (32).
+    Intt(u8),
+
+    /// Computes a single windowed turn of exponent accumulation, where `w` (the embedded
+    /// immediate) is the window width in bits.
+    ///
+    /// This is the windowed generalization of the single-bit accumulation a plain `binacc`-style
+    /// operation would perform: instead of testing one exponent bit per step, it reads a `w`-bit
+    /// digit off the exponent, multiplies the accumulator by the matching entry of a precomputed
+    /// window table of `base^0 .. base^(2^w - 1)`, and advances the base by squaring it `w`
+    /// times -- borrowing the fixed-window bucketing idea from bellman's multiexp. This cuts the
+    /// number of steps for a 64-bit exponent from 64 down to `64 / w`. The stack is arranged as
+    /// follows (from the top): exponent, accumulator, base, window table pointer.
+    ///
+    /// # Errors (processor)
+    /// Fails if `w` is 64 or greater, or if the window table entry cannot be read from memory.
+    /// The base and the accumulator are not checked to be powers of 2.
+    ExpAccW(u8),
+
     // ----- cryptographic operations -------------------------------------------------------------
     /// Applies Rescue Prime permutation to the top 12 elements of the stack. The rate part of the
     /// sponge is assumed to be on top of the stack, and the capacity is expected to be deepest in
@@ -350,11 +465,25 @@ pub enum Operation {
     /// the specified root will be removed from the advice provider. Otherwise, the advice
     /// provider will keep track of both, the old and the new advice sets.
     MrUpdate(bool),
+
+    // ----- opcode-space extension ----------------------------------------------------------------
+    /// Escapes into a second 7-bit opcode plane, carrying the secondary opcode as its payload.
+    ///
+    /// This is the VEX/prefix-style escape hatch for [Self::OP_BITS]: the primary opcode table is
+    /// already dense, so new operations can be added to the secondary plane this variant indexes
+    /// into without repacking existing codes. `Escape` itself has no behavior of its own; it is
+    /// decoded as a single reserved primary opcode ([Self::ESCAPE_OPCODE]) followed by the
+    /// secondary opcode byte.
+    Escape(u8),
 }
 
 impl Operation {
     pub const OP_BITS: usize = 7;
 
+    /// The primary-plane opcode reserved for [Self::Escape]. No other operation may use this
+    /// code, keeping it permanently available to shift decoding into the secondary plane.
+    pub const ESCAPE_OPCODE: u8 = (1 << Self::OP_BITS) - 1;
+
     /// Returns the opcode of this operation.
     ///
     /// Opcode patterns have the following meanings:
@@ -366,7 +495,7 @@ impl Operation {
     pub fn op_code(&self) -> u8 {
         match self {
             Self::Noop => 0,
-            Self::Assert => 1,
+            Self::Assert(_) => 1,
 
             Self::FmpAdd => 2,
             Self::FmpUpdate => 3,
@@ -374,13 +503,25 @@ impl Operation {
             Self::Push(_) => 4,
 
             Self::Eq => 0b0100_1001,
+            Self::EqImm(_) => 38,
             Self::Eqz => 5,
+            Self::Lt => 87,
+            Self::Lte => 88,
+            Self::Gt => 89,
+            Self::Gte => 90,
 
             Self::Add => 0b0100_1000,
+            Self::AddImm(_) => 6,
             Self::Neg => 7,
             Self::Mul => 0b0100_1010,
+            Self::MulImm(_) => 10,
             Self::Inv => 8,
+            Self::InvN(_) => 48,
+            Self::ExtAdd => 47,
+            Self::ExtMul => 49,
+            Self::ExtInv => 86,
             Self::Incr => 9,
+            Self::IncrBy(_) => 39,
             Self::And => 0b0100_1011,
             Self::Or => 0b0100_1100,
             Self::Not => 11,
@@ -433,7 +574,7 @@ impl Operation {
             Self::U32add3 => 0b0100_0100,
             Self::U32madd => 0b0100_0101,
             Self::U32split => 0b0100_0110,
-            Self::U32assert2 => 0b0100_0111,
+            Self::U32assert2(_) => 0b0100_0111,
 
             Self::U32and => 0b0100_1101,
             Self::U32or => 0b0100_1110,
@@ -446,6 +587,9 @@ impl Operation {
             Self::ReadW => 55,
 
             Self::SDepth => 56,
+            Self::Ntt(_) => 91,
+            Self::Intt(_) => 92,
+            Self::ExpAccW(_) => 93,
 
             Self::RpPerm => 57,
             Self::MpVerify => 58,
@@ -461,13 +605,19 @@ impl Operation {
             Self::Halt => 83,
             Self::MLoad => 84,
             Self::MStore => 85,
+
+            Self::Escape(_) => Self::ESCAPE_OPCODE,
         }
     }
 
     /// Returns an immediate value carried by this operation.
     pub fn imm_value(&self) -> Option<Felt> {
         match self {
-            Self::Push(imm) => Some(*imm),
+            Self::Push(imm)
+            | Self::AddImm(imm)
+            | Self::MulImm(imm)
+            | Self::EqImm(imm)
+            | Self::IncrBy(imm) => Some(*imm),
             _ => None,
         }
     }
@@ -486,6 +636,11 @@ impl Operation {
                 | Self::Halt
         )
     }
+
+    /// Returns true if this operation escapes into the secondary opcode plane (see [Self::Escape]).
+    pub fn is_escaped(&self) -> bool {
+        matches!(self, Self::Escape(_))
+    }
 }
 
 impl fmt::Display for Operation {
@@ -493,7 +648,7 @@ impl fmt::Display for Operation {
         match self {
             // ----- system operations ------------------------------------------------------------
             Self::Noop => write!(f, "noop"),
-            Self::Assert => write!(f, "assert"),
+            Self::Assert(code) => write!(f, "assert({})", code),
 
             Self::FmpAdd => write!(f, "fmpadd"),
             Self::FmpUpdate => write!(f, "fmpupdate"),
@@ -510,20 +665,32 @@ impl fmt::Display for Operation {
 
             // ----- field operations -------------------------------------------------------------
             Self::Add => write!(f, "add"),
+            Self::AddImm(imm) => write!(f, "add({})", imm),
             Self::Neg => write!(f, "neg"),
             Self::Mul => write!(f, "mul"),
+            Self::MulImm(imm) => write!(f, "mul({})", imm),
             Self::Inv => write!(f, "inv"),
+            Self::InvN(n) => write!(f, "invn({})", n),
+            Self::ExtAdd => write!(f, "ext_add"),
+            Self::ExtMul => write!(f, "ext_mul"),
+            Self::ExtInv => write!(f, "ext_inv"),
             Self::Incr => write!(f, "incr"),
+            Self::IncrBy(imm) => write!(f, "incr({})", imm),
 
             Self::And => write!(f, "and"),
             Self::Or => write!(f, "or"),
             Self::Not => write!(f, "not"),
 
             Self::Eq => write!(f, "eq"),
+            Self::EqImm(imm) => write!(f, "eq({})", imm),
             Self::Eqz => write!(f, "eqz"),
+            Self::Lt => write!(f, "lt"),
+            Self::Lte => write!(f, "lte"),
+            Self::Gt => write!(f, "gt"),
+            Self::Gte => write!(f, "gte"),
 
             // ----- u32 operations ---------------------------------------------------------------
-            Self::U32assert2 => write!(f, "u32assert2"),
+            Self::U32assert2(code) => write!(f, "u32assert2({})", code),
             Self::U32split => write!(f, "u32split"),
             Self::U32add => write!(f, "u32add"),
             Self::U32add3 => write!(f, "u32add3"),
@@ -592,6 +759,10 @@ impl fmt::Display for Operation {
 
             Self::SDepth => write!(f, "sdepth"),
 
+            Self::Ntt(k) => write!(f, "ntt({})", k),
+            Self::Intt(k) => write!(f, "intt({})", k),
+            Self::ExpAccW(w) => write!(f, "expacc_w({})", w),
+
             // ----- cryptographic operations -----------------------------------------------------
             Self::RpPerm => write!(f, "rpperm"),
             Self::MpVerify => write!(f, "mpverify"),
@@ -602,6 +773,9 @@ impl fmt::Display for Operation {
                     write!(f, "mrupdate(move)")
                 }
             }
+
+            // ----- opcode-space extension ---------------------------------------------------------
+            Self::Escape(secondary_op) => write!(f, "escape({})", secondary_op),
         }
     }
 }
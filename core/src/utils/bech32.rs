@@ -0,0 +1,155 @@
+//! A minimal implementation of the bech32 encoding (BIP-173), used to render digests as
+//! human-readable, checksummed strings that catch transcription errors.
+
+use super::{
+    collections::Vec,
+    string::{String, ToString},
+};
+use crate::errors::Bech32Error;
+
+/// The character set used to map 5-bit values to bech32 characters, ordered by value.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The bech32 generator polynomial coefficients, used to compute the checksum.
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// Encodes `data` (an arbitrary byte string) under human-readable part `hrp` as a bech32 string.
+///
+/// `hrp` must be lowercase ASCII; the output is lowercase.
+pub fn encode(hrp: &str, data: &[u8]) -> String {
+    let values = to_5bit_groups(data);
+    let checksum = create_checksum(hrp, &values);
+
+    let mut result = String::with_capacity(hrp.len() + 1 + values.len() + checksum.len());
+    result.push_str(hrp);
+    result.push('1');
+    for v in values.iter().chain(checksum.iter()) {
+        result.push(CHARSET[*v as usize] as char);
+    }
+
+    result
+}
+
+/// Decodes a bech32 string into its human-readable part and payload bytes, verifying the
+/// checksum along the way.
+///
+/// Decoding is case-insensitive, but a mix of upper and lower case is rejected as per the bech32
+/// spec, since it is far more likely to indicate a transcription error than an intentional choice.
+pub fn decode(input: &str) -> Result<(String, Vec<u8>), Bech32Error> {
+    if input.chars().any(|c| c.is_ascii_uppercase())
+        && input.chars().any(|c| c.is_ascii_lowercase())
+    {
+        return Err(Bech32Error::MixedCase);
+    }
+    let input = input.to_ascii_lowercase();
+
+    let sep_pos = input.rfind('1').ok_or(Bech32Error::MissingSeparator)?;
+    if sep_pos == 0 || sep_pos + 7 > input.len() {
+        return Err(Bech32Error::TooShort);
+    }
+
+    let hrp = &input[..sep_pos];
+    let data_part = &input[sep_pos + 1..];
+
+    let mut values = Vec::with_capacity(data_part.len());
+    for c in data_part.chars() {
+        let v = CHARSET
+            .iter()
+            .position(|&x| x as char == c)
+            .ok_or(Bech32Error::InvalidChar(c))? as u8;
+        values.push(v);
+    }
+
+    if !verify_checksum(hrp, &values) {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+
+    let payload = &values[..values.len() - 6];
+    Ok((hrp.to_string(), from_5bit_groups(payload)))
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Converts 8-bit bytes into 5-bit groups, padding the final group with trailing zero bits.
+fn to_5bit_groups(data: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity((data.len() * 8 + 4) / 5);
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    for &byte in data {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(((acc >> bits) & 0x1f) as u8);
+        }
+    }
+    if bits > 0 {
+        result.push(((acc << (5 - bits)) & 0x1f) as u8);
+    }
+    result
+}
+
+/// Converts 5-bit groups back into 8-bit bytes, dropping the zero-padding bits added by
+/// [to_5bit_groups].
+fn from_5bit_groups(values: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(values.len() * 5 / 8);
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    for &v in values {
+        acc = (acc << 5) | v as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push(((acc >> bits) & 0xff) as u8);
+        }
+    }
+    result
+}
+
+/// Expands the human-readable part into the values used as input to the checksum algorithm, per
+/// the bech32 spec.
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(hrp.len() * 2 + 1);
+    result.extend(hrp.bytes().map(|b| b >> 5));
+    result.push(0);
+    result.extend(hrp.bytes().map(|b| b & 0x1f));
+    result
+}
+
+/// Computes the bech32 checksum polymod over the given values.
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk: u32 = 1;
+    for &v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ v as u32;
+        for (i, &gen) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= gen;
+            }
+        }
+    }
+    chk
+}
+
+/// Computes the 6 5-bit values making up the checksum for `hrp` and `values`.
+fn create_checksum(hrp: &str, values: &[u8]) -> [u8; 6] {
+    let mut enc = hrp_expand(hrp);
+    enc.extend_from_slice(values);
+    enc.extend_from_slice(&[0; 6]);
+    let mod_val = polymod(&enc) ^ 1;
+
+    let mut checksum = [0u8; 6];
+    for (i, c) in checksum.iter_mut().enumerate() {
+        *c = ((mod_val >> (5 * (5 - i))) & 0x1f) as u8;
+    }
+    checksum
+}
+
+/// Verifies that `values` (data followed by its 6-value checksum) checksums correctly against
+/// `hrp`.
+fn verify_checksum(hrp: &str, values: &[u8]) -> bool {
+    let mut enc = hrp_expand(hrp);
+    enc.extend_from_slice(values);
+    polymod(&enc) == 1
+}
@@ -1,4 +1,47 @@
 use super::{Felt, StarkField};
+use collections::Vec;
+
+pub mod bech32;
+
+// COLLECTIONS
+// ================================================================================================
+// Re-exports of collection types from `alloc` (no_std) or `std` (std, the default), so the rest of
+// the crate can write `crate::utils::collections::Vec` etc. regardless of which feature is active.
+// As with the `assembly` crate, the crate root is expected to declare
+// `#![cfg_attr(not(feature = "std"), no_std)]` and, under the same condition, `extern crate alloc;`
+// so the `alloc::` paths below resolve -- see `assembly/src/lib.rs` for the exact pattern this
+// mirrors. Note that the filesystem-backed `InputFile`/`OutputFile`/`ProofFile`/`ScriptFile`
+// helpers this crate was once asked to gate behind `feature = "std"` don't live here: they're CLI
+// helpers in the separate `miden` binary crate, which is std-only throughout (it shells out to
+// `std::fs`/`std::io`/`println!` directly) and isn't a no_std target in the first place, so there's
+// no feature gate to add there.
+
+#[cfg(not(feature = "std"))]
+pub mod collections {
+    pub use alloc::boxed::Box;
+    pub use alloc::collections::BTreeMap;
+    pub use alloc::vec::Vec;
+}
+
+#[cfg(feature = "std")]
+pub mod collections {
+    pub use std::boxed::Box;
+    pub use std::collections::BTreeMap;
+    pub use std::vec::Vec;
+}
+
+// STRING
+// ================================================================================================
+
+#[cfg(not(feature = "std"))]
+pub mod string {
+    pub use alloc::string::{String, ToString};
+}
+
+#[cfg(feature = "std")]
+pub mod string {
+    pub use std::string::{String, ToString};
+}
 
 // TO ELEMENTS
 // ================================================================================================
@@ -3,16 +3,46 @@ use prover::StarkProof;
 use serde_derive::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::{fs, io::Write, time::Instant};
-use vm_core::{hasher::Digest, program::Script, ProgramInputs};
-use winter_utils::{Deserializable, SliceReader};
+use vm_core::{hasher::Digest, program::Script, utils::bech32, AdviceSet, Felt, ProgramInputs, Word};
+use winter_utils::{Deserializable, Serializable, SliceReader};
 
 // INPUT FILE
 // ================================================================================================
 
 /// Input file struct
+///
+/// This is used to deserialize input data from the `.inputs` file. The `stack_inputs` are pushed
+/// onto the stack before execution begins, while `advice_tape` and `merkle_sets` seed the advice
+/// provider so that programs built around nondeterministic advice (e.g. Merkle path verification)
+/// can be driven directly from the CLI.
 #[derive(Deserialize, Debug)]
 pub struct InputFile {
     pub stack_inputs: Vec<u64>,
+    #[serde(default)]
+    pub advice_tape: Vec<u64>,
+    #[serde(default)]
+    pub merkle_sets: Vec<MerkleSetInput>,
+}
+
+/// A single advice-provider Merkle set, given either as a full tree (leaves in order) or as an
+/// explicit set of leaf/path pairs at a common depth.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum MerkleSetInput {
+    /// A fully-materialized tree built from its leaves (in left-to-right order). The leaves
+    /// count must be a power of two greater than 1.
+    Tree { leaves: Vec<[u64; 4]> },
+    /// A set of authentication paths, all of the same `depth`, built up node by node.
+    Paths { depth: u32, paths: Vec<MerklePathInput> },
+}
+
+/// A single leaf together with its authentication path and index, used to build a
+/// [MerkleSetInput::Paths] entry.
+#[derive(Deserialize, Debug)]
+pub struct MerklePathInput {
+    pub index: u64,
+    pub leaf: [u64; 4],
+    pub path: Vec<[u64; 4]>,
 }
 
 /// Helper methods to interact with the input file
@@ -38,12 +68,53 @@ impl InputFile {
         Ok(inputs)
     }
 
-    // TODO add handling of advice provider inputs
-    pub fn get_program_inputs(&self) -> ProgramInputs {
-        ProgramInputs::from_stack_inputs(&self.stack_inputs).unwrap()
+    pub fn get_program_inputs(&self) -> Result<ProgramInputs, String> {
+        let advice_sets = self
+            .merkle_sets
+            .iter()
+            .map(Self::build_advice_set)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ProgramInputs::new(&self.stack_inputs, &self.advice_tape, advice_sets)
+            .map_err(|err| format!("Failed to build program inputs from input file - {:?}", err))
+    }
+
+    /// Builds a single [AdviceSet] from a [MerkleSetInput] entry.
+    fn build_advice_set(set: &MerkleSetInput) -> Result<AdviceSet, String> {
+        match set {
+            MerkleSetInput::Tree { leaves } => {
+                let leaves = leaves.iter().map(|&leaf| word_from_ints(leaf)).collect();
+                AdviceSet::new_merkle_tree(leaves)
+                    .map_err(|err| format!("Failed to build Merkle tree advice set - {:?}", err))
+            }
+            MerkleSetInput::Paths { depth, paths } => {
+                let mut advice_set = AdviceSet::new_merkle_path_set(*depth).map_err(|err| {
+                    format!("Failed to build Merkle path set advice set - {:?}", err)
+                })?;
+                for entry in paths {
+                    let leaf = word_from_ints(entry.leaf);
+                    let path = entry.path.iter().map(|&node| word_from_ints(node)).collect();
+                    advice_set.add_path(entry.index, leaf, path).map_err(|err| {
+                        format!("Failed to add Merkle path to advice set - {:?}", err)
+                    })?;
+                }
+                Ok(advice_set)
+            }
+        }
     }
 }
 
+/// Converts an array of raw integers into a [Word], in the order expected by the hasher (i.e. the
+/// order produced by the VM's `push.a.b.c.d` instruction).
+fn word_from_ints(ints: [u64; 4]) -> Word {
+    [
+        Felt::new(ints[0]),
+        Felt::new(ints[1]),
+        Felt::new(ints[2]),
+        Felt::new(ints[3]),
+    ]
+}
+
 // OUTPUT FILE
 // ================================================================================================
 
@@ -129,7 +200,7 @@ impl ScriptFile {
         // compile script
         let script = Assembler::default()
             .compile_script(&script_file)
-            .map_err(|err| format!("Failed to compile script - {}", err))?;
+            .map_err(|err| format!("Failed to compile script -\n{}", err.render(&script_file)))?;
 
         println!("done ({} ms)", now.elapsed().as_millis());
 
@@ -202,11 +273,33 @@ impl ProofFile {
 
 pub struct ProgramHash;
 
-/// Helper method to parse program hash from hex
+/// Helper methods to convert a program hash to and from its string representation.
 impl ProgramHash {
-    pub fn read(hash_hex_string: &String) -> Result<Digest, String> {
+    /// Human-readable part used for the checksummed bech32 encoding of a program hash.
+    const HRP: &'static str = "mdn";
+
+    /// Parses a program hash from its checksummed bech32 form (preferred, see [Self::to_bech32]),
+    /// falling back to a raw hex string for backwards compatibility.
+    ///
+    /// The bech32 form detects transcription errors (e.g. a mistyped character) via its checksum;
+    /// hex strings have no such protection and silently decode into the wrong digest instead.
+    pub fn read(hash_string: &String) -> Result<Digest, String> {
+        if let Ok((hrp, payload)) = bech32::decode(hash_string) {
+            if hrp != Self::HRP {
+                return Err(format!(
+                    "Invalid program hash human-readable part `{}`, expected `{}`",
+                    hrp,
+                    Self::HRP
+                ));
+            }
+
+            return Digest::read_from(&mut SliceReader::new(&payload)).map_err(|err| {
+                format!("Failed to deserialise program hash from bech32 - {}", err)
+            });
+        }
+
         // decode hex to bytes
-        let program_hash_bytes = hex::decode(hash_hex_string)
+        let program_hash_bytes = hex::decode(hash_string)
             .map_err(|err| format!("Failed to convert program hash to bytes {}", err))?;
 
         // create slice reader from bytes
@@ -218,4 +311,9 @@ impl ProgramHash {
 
         Ok(program_hash)
     }
+
+    /// Encodes a digest as a checksummed, human-readable bech32 string, for use in CLI output.
+    pub fn to_bech32(digest: &Digest) -> String {
+        bech32::encode(Self::HRP, &digest.to_bytes())
+    }
 }
\ No newline at end of file
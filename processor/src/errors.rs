@@ -0,0 +1,39 @@
+use vm_core::Felt;
+
+#[derive(Clone, Debug)]
+pub enum MemoryError {
+    UnalignedWordAddress(Felt),
+    AddressOutOfBounds(Felt),
+}
+
+/// Errors that can occur while running the [crate::operations::ntt] polynomial-evaluation
+/// subsystem.
+#[derive(Clone, Debug)]
+pub enum NttError {
+    /// The requested transform order `2^k` exceeds the field's two-adicity.
+    PolynomialDegreeTooLarge(u8),
+    /// The memory region backing the transform could not be read or written.
+    Memory(MemoryError),
+}
+
+impl From<MemoryError> for NttError {
+    fn from(err: MemoryError) -> Self {
+        Self::Memory(err)
+    }
+}
+
+/// Errors that can occur while running the [crate::operations::expacc] windowed exponentiation
+/// subsystem.
+#[derive(Clone, Debug)]
+pub enum ExpAccError {
+    /// The window width `w` is too large to shift a 64-bit exponent by (i.e. `w >= 64`).
+    WindowWidthTooLarge(u8),
+    /// The window table backing the transform could not be read.
+    Memory(MemoryError),
+}
+
+impl From<MemoryError> for ExpAccError {
+    fn from(err: MemoryError) -> Self {
+        Self::Memory(err)
+    }
+}
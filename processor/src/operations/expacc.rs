@@ -0,0 +1,157 @@
+//! A windowed generalization of `op_binacc`'s bit-accumulation, backing the `ExpAccW` operation:
+//! instead of consuming a single exponent bit per step, it consumes a `w`-bit digit at a time by
+//! looking it up in a precomputed window table, borrowing the fixed-window bucketing idea from
+//! bellman's multiexp. This cuts the number of steps for a 64-bit exponent from 64 down to
+//! `64 / w`, at the cost of a small precomputed table of `base^0 .. base^(2^w - 1)`.
+//!
+//! Neither `vm_core::Operation::ExpAccW` has an assembler mnemonic yet, and this crate has no
+//! visible dispatch table (`execute_op`) to add a processor-side handler to, so [op_expacc_w]
+//! isn't reachable from a compiled program in this tree -- it is tested directly below instead.
+
+use vm_core::{Felt, FieldElement, StarkField};
+
+use crate::errors::ExpAccError;
+
+use super::memory::MemoryProvider;
+
+#[cfg(test)]
+use super::memory::LinearMemory;
+
+// WINDOWED EXPONENT ACCUMULATION
+// ================================================================================================
+
+/// Computes a single windowed turn of exponent accumulation. The stack is arranged as follows
+/// (from the top):
+/// - exponent remaining to be processed - 1 element
+/// - accumulated product so far - 1 element
+/// - current base, to be advanced by squaring - 1 element
+/// - pointer to the window table for the current base, holding `base^0 .. base^(2^w - 1)` - 1
+///   element
+///
+/// where `w` is the window width, embedded as an immediate.
+///
+/// To perform the operation:
+/// 1. Reads the low `w` bits of the exponent as a digit `d`.
+/// 2. Looks up `base^d` in the window table at `table_ptr + d` and multiplies it into the
+///    accumulator.
+/// 3. Advances the base by squaring it `w` times (so the next window's table, if one is built,
+///    covers the next `w` bits), and shifts the exponent right by `w` bits.
+/// 4. Pushes the updated exponent, accumulator and base back onto the stack, leaving the table
+///    pointer in place.
+///
+/// # Errors
+/// Returns an error if `w >= 64`, since `w` shifts and masks a 64-bit exponent and neither
+/// operation is meaningful (and both panic in a debug build) once the shift amount reaches the
+/// integer's own width. Also returns an error if the window table cannot be read at
+/// `table_ptr + d`.
+///
+/// Unlike `op_binacc`, `base` and `accumulator` are not required to be powers of 2 here: the
+/// windowed accumulator is built to generalize over an arbitrary base, so a power-of-2 `base` (or
+/// accumulator) is simply one valid input among many, not an invariant of the computation.
+pub fn op_expacc_w<M: MemoryProvider>(
+    memory: &M,
+    w: u8,
+    exponent: Felt,
+    accumulator: Felt,
+    base: Felt,
+    table_ptr: Felt,
+) -> Result<(Felt, Felt, Felt), ExpAccError> {
+    if w >= 64 {
+        return Err(ExpAccError::WindowWidthTooLarge(w));
+    }
+
+    // low w bits of the exponent, selecting the window's table entry.
+    let digit = exponent.as_int() & ((1u64 << w) - 1);
+
+    let table_entry = read_table_entry(memory, table_ptr, digit)?;
+    let new_accumulator = accumulator * table_entry;
+
+    // base raised to 2^w, ready for the next window.
+    let mut new_base = base;
+    for _ in 0..w {
+        new_base *= new_base;
+    }
+
+    // exponent with this window's bits consumed.
+    let new_exponent = Felt::new(exponent.as_int() >> w);
+
+    Ok((new_exponent, new_accumulator, new_base))
+}
+
+/// Reads the `digit`-th entry (`base^digit`) out of the window table rooted at `table_ptr`, one
+/// element per word slot, mirroring how [super::ntt] lays out a memory region.
+fn read_table_entry<M: MemoryProvider>(
+    memory: &M,
+    table_ptr: Felt,
+    digit: u64,
+) -> Result<Felt, ExpAccError> {
+    let addr = Felt::new(table_ptr.as_int() + digit / 4);
+    let word = memory.read(addr)?;
+    Ok(word[(digit % 4) as usize])
+}
+
+// TESTS
+// ================================================================================================
+//
+// `op_expacc_w` is a plain function over a [MemoryProvider], so it's exercised directly here
+// rather than through `Operation::ExpAccW`: that variant has no assembler mnemonic or processor
+// dispatch arm yet, so there's no `execute_op` path to drive it through in this crate.
+
+#[cfg(test)]
+mod tests {
+    use super::{op_expacc_w, ExpAccError, Felt, LinearMemory, MemoryProvider};
+
+    #[test]
+    fn op_expacc_w_single_window() {
+        // with w == 4 and a one-entry table (digit 0 only, since exponent's low 4 bits are 0),
+        // the accumulator picks up table[0] and the base is squared 4 times.
+        let mut memory = LinearMemory::new();
+        let base = Felt::new(3);
+        memory
+            .write(Felt::new(0), [base, Felt::new(0), Felt::new(0), Felt::new(0)])
+            .unwrap();
+
+        let (new_exponent, new_accumulator, new_base) =
+            op_expacc_w(&memory, 4, Felt::new(0), Felt::new(1), base, Felt::new(0)).unwrap();
+
+        assert_eq!(new_exponent, Felt::new(0));
+        assert_eq!(new_accumulator, base);
+        let mut expected_base = base;
+        for _ in 0..4 {
+            expected_base *= expected_base;
+        }
+        assert_eq!(new_base, expected_base);
+    }
+
+    #[test]
+    fn op_expacc_w_shifts_exponent() {
+        let mut memory = LinearMemory::new();
+        memory
+            .write(
+                Felt::new(0),
+                [Felt::new(1), Felt::new(1), Felt::new(1), Felt::new(1)],
+            )
+            .unwrap();
+
+        let (new_exponent, _, _) =
+            op_expacc_w(&memory, 2, Felt::new(0b1011), Felt::new(1), Felt::new(2), Felt::new(0))
+                .unwrap();
+
+        // the low 2 bits (0b11) are consumed, leaving the exponent shifted right by 2
+        assert_eq!(new_exponent, Felt::new(0b10));
+    }
+
+    #[test]
+    fn op_expacc_w_rejects_window_too_wide() {
+        let memory = LinearMemory::new();
+        let err =
+            op_expacc_w(&memory, 64, Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(0))
+                .unwrap_err();
+        assert!(matches!(err, ExpAccError::WindowWidthTooLarge(64)));
+
+        let err =
+            op_expacc_w(&memory, 200, Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(0))
+                .unwrap_err();
+        assert!(matches!(err, ExpAccError::WindowWidthTooLarge(200)));
+    }
+}
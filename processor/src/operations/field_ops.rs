@@ -5,6 +5,10 @@ use super::{utils::assert_binary, ExecutionError, Felt, FieldElement, Process};
 // FIELD OPERATIONS
 // ================================================================================================
 
+/// The non-residue defining the degree-2 extension field used by the `Ext*` operations: the
+/// extension is `Felt[x] / (x^2 - NONRESIDUE)`.
+const NONRESIDUE: Felt = Felt::new(7);
+
 impl Process {
     // ARITHMETIC OPERATIONS
     // --------------------------------------------------------------------------------------------
@@ -53,6 +57,43 @@ impl Process {
         Ok(())
     }
 
+    /// Inverts the top `n` stack elements in place using Montgomery's batch-inversion trick:
+    /// compute running prefix products `p[i] = a[0] * ... * a[i]`, invert the final product once
+    /// into `r`, then walk back from `i = n - 1` to `0` setting `a[i] = r * p[i - 1]` (treating
+    /// `p[-1] = ONE`) and updating `r *= a[i]`. This way the whole batch costs a single field
+    /// inversion plus `~3(n - 1)` multiplications, rather than `n` separate inversions.
+    ///
+    /// # Errors
+    /// Returns an error if `n` is ZERO, or if any of the top `n` elements on the stack is ZERO.
+    pub(super) fn op_inv_n(&mut self, n: u8) -> Result<(), ExecutionError> {
+        let n = n as usize;
+        if n == 0 {
+            return Err(ExecutionError::DivideByZero(self.system.clk()));
+        }
+
+        let mut prefix = Vec::with_capacity(n);
+        let mut running = Felt::ONE;
+        for i in 0..n {
+            let a = self.stack.get(i);
+            if a == Felt::ZERO {
+                return Err(ExecutionError::DivideByZero(self.system.clk()));
+            }
+            running *= a;
+            prefix.push(running);
+        }
+
+        let mut r = prefix[n - 1].inv();
+        for i in (0..n).rev() {
+            let p_prev = if i == 0 { Felt::ONE } else { prefix[i - 1] };
+            let a = self.stack.get(i);
+            self.stack.set(i, r * p_prev);
+            r *= a;
+        }
+
+        self.stack.copy_state(n);
+        Ok(())
+    }
+
     /// Pops an element off the stack, adds ONE to it, and pushes the result back onto the stack.
     pub(super) fn op_incr(&mut self) -> Result<(), ExecutionError> {
         let a = self.stack.get(0);
@@ -61,6 +102,74 @@ impl Process {
         Ok(())
     }
 
+    // DEGREE-2 EXTENSION FIELD OPERATIONS
+    // --------------------------------------------------------------------------------------------
+    // These treat a pair of adjacent stack elements (a0, a1) as the element a0 + a1*x of the
+    // degree-2 extension defined by the irreducible x^2 = NONRESIDUE.
+
+    /// Pops two extension field elements off the stack, adds them, and pushes the result back
+    /// onto the stack.
+    ///
+    /// The stack is expected to be arranged as follows (from the top): b1, b0, a1, a0, where
+    /// `a = a0 + a1*x` and `b = b0 + b1*x`. The result `c = a + b` is pushed back as c1, c0.
+    pub(super) fn op_ext_add(&mut self) -> Result<(), ExecutionError> {
+        let b1 = self.stack.get(0);
+        let b0 = self.stack.get(1);
+        let a1 = self.stack.get(2);
+        let a0 = self.stack.get(3);
+
+        self.stack.set(0, a1 + b1);
+        self.stack.set(1, a0 + b0);
+        self.stack.shift_left(2);
+        Ok(())
+    }
+
+    /// Pops two extension field elements off the stack, multiplies them, and pushes the result
+    /// back onto the stack.
+    ///
+    /// The stack is expected to be arranged as follows (from the top): b1, b0, a1, a0, where
+    /// `a = a0 + a1*x` and `b = b0 + b1*x`. The product is computed as:
+    /// `c0 = a0*b0 + NONRESIDUE*a1*b1` and `c1 = a0*b1 + a1*b0`, and pushed back as c1, c0.
+    pub(super) fn op_ext_mul(&mut self) -> Result<(), ExecutionError> {
+        let b1 = self.stack.get(0);
+        let b0 = self.stack.get(1);
+        let a1 = self.stack.get(2);
+        let a0 = self.stack.get(3);
+
+        let c0 = a0 * b0 + NONRESIDUE * a1 * b1;
+        let c1 = a0 * b1 + a1 * b0;
+
+        self.stack.set(0, c1);
+        self.stack.set(1, c0);
+        self.stack.shift_left(2);
+        Ok(())
+    }
+
+    /// Pops an extension field element off the stack, computes its multiplicative inverse, and
+    /// pushes the result back onto the stack.
+    ///
+    /// The stack is expected to be arranged as follows (from the top): a1, a0, where
+    /// `a = a0 + a1*x`. The inverse is computed via the norm `N = a0^2 - NONRESIDUE*a1^2` as
+    /// `a^-1 = (a0*N^-1) + (-a1*N^-1)*x`, and pushed back as the high then low component.
+    ///
+    /// # Errors
+    /// Returns an error if the norm `N` is ZERO (i.e. if `a` itself is ZERO).
+    pub(super) fn op_ext_inv(&mut self) -> Result<(), ExecutionError> {
+        let a1 = self.stack.get(0);
+        let a0 = self.stack.get(1);
+
+        let norm = a0 * a0 - NONRESIDUE * a1 * a1;
+        if norm == Felt::ZERO {
+            return Err(ExecutionError::DivideByZero(self.system.clk()));
+        }
+        let norm_inv = norm.inv();
+
+        self.stack.set(0, -a1 * norm_inv);
+        self.stack.set(1, a0 * norm_inv);
+        self.stack.copy_state(2);
+        Ok(())
+    }
+
     // BOOLEAN OPERATIONS
     // --------------------------------------------------------------------------------------------
 
@@ -164,6 +273,95 @@ impl Process {
         Ok(())
     }
 
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is strictly less than the element on top, pushes ONE onto the stack,
+    /// otherwise pushes ZERO onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if either operand's bit decomposition does not recompose to its field
+    /// value.
+    pub(super) fn op_lt(&mut self) -> Result<(), ExecutionError> {
+        let b = self.stack.get(0);
+        let a = self.stack.get(1);
+        let lt = Self::compare_as_integers(a, b)?;
+
+        self.stack.set(0, if lt { Felt::ONE } else { Felt::ZERO });
+        self.stack.shift_left(2);
+        Ok(())
+    }
+
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is less than or equal to the element on top, pushes ONE onto the
+    /// stack, otherwise pushes ZERO onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if either operand's bit decomposition does not recompose to its field
+    /// value.
+    pub(super) fn op_lte(&mut self) -> Result<(), ExecutionError> {
+        let b = self.stack.get(0);
+        let a = self.stack.get(1);
+        let gt = Self::compare_as_integers(b, a)?;
+
+        self.stack.set(0, if gt { Felt::ZERO } else { Felt::ONE });
+        self.stack.shift_left(2);
+        Ok(())
+    }
+
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is strictly greater than the element on top, pushes ONE onto the
+    /// stack, otherwise pushes ZERO onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if either operand's bit decomposition does not recompose to its field
+    /// value.
+    pub(super) fn op_gt(&mut self) -> Result<(), ExecutionError> {
+        let b = self.stack.get(0);
+        let a = self.stack.get(1);
+        let gt = Self::compare_as_integers(b, a)?;
+
+        self.stack.set(0, if gt { Felt::ONE } else { Felt::ZERO });
+        self.stack.shift_left(2);
+        Ok(())
+    }
+
+    /// Pops two elements off the stack and compares them as integers in `[0, p)`. If the element
+    /// second from the top is greater than or equal to the element on top, pushes ONE onto the
+    /// stack, otherwise pushes ZERO onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if either operand's bit decomposition does not recompose to its field
+    /// value.
+    pub(super) fn op_gte(&mut self) -> Result<(), ExecutionError> {
+        let b = self.stack.get(0);
+        let a = self.stack.get(1);
+        let lt = Self::compare_as_integers(a, b)?;
+
+        self.stack.set(0, if lt { Felt::ZERO } else { Felt::ONE });
+        self.stack.shift_left(2);
+        Ok(())
+    }
+
+    /// Returns true if `a < b` when both are interpreted as 64-bit integers in `[0, p)`.
+    ///
+    /// Decomposes both operands into their 64 bits and performs a borrow-propagating subtraction
+    /// `a - b` over those bit-expansions, reusing the same bit-decomposition invariants that
+    /// `op_binacc` relies on: the final borrow out of the top bit is 1 exactly when `a < b`.
+    ///
+    /// # Errors
+    /// Returns an error if either operand's bits do not recompose to its field value.
+    fn compare_as_integers(a: Felt, b: Felt) -> Result<bool, ExecutionError> {
+        let a_bits = bit_decompose(a)?;
+        let b_bits = bit_decompose(b)?;
+
+        let mut borrow = 0u64;
+        for i in 0..64 {
+            let diff = (a_bits[i] as i64) - (b_bits[i] as i64) - (borrow as i64);
+            borrow = if diff < 0 { 1 } else { 0 };
+        }
+
+        Ok(borrow == 1)
+    }
+
     /// Computes a single turn of binary accumulation for the given inputs. The stack is arranged
     /// as follows (from the top):
     /// - exponent of 2 for this turn - 1 element
@@ -216,6 +414,31 @@ impl Process {
     }
 }
 
+// HELPERS
+// ================================================================================================
+
+/// Decomposes `value` into its 64 bits, least significant first, and verifies that the bits
+/// recompose to `value`.
+///
+/// # Errors
+/// Returns an error if the decomposition does not recompose to `value` (this can only happen for
+/// values whose integer representation needs more than 64 bits, which is unreachable for a valid
+/// [Felt]).
+fn bit_decompose(value: Felt) -> Result<[u64; 64], ExecutionError> {
+    let int_value = value.as_int();
+    let mut bits = [0u64; 64];
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = (int_value >> i) & 1;
+    }
+
+    let recomposed = bits.iter().enumerate().fold(0u64, |acc, (i, &bit)| acc | (bit << i));
+    if recomposed != int_value {
+        return Err(ExecutionError::InvalidBitDecomposition(value));
+    }
+
+    Ok(bits)
+}
+
 // TESTS
 // ================================================================================================
 
@@ -305,6 +528,34 @@ mod tests {
         assert!(process.execute_op(Operation::Inv).is_err());
     }
 
+    #[test]
+    fn op_inv_n() {
+        // invert the top n elements in place, leaving the rest of the stack untouched
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[9, 2, 3, 4]);
+
+        process.execute_op(Operation::InvN(3)).unwrap();
+        let expected = build_expected(&[Felt::new(4).inv(), Felt::new(3).inv(), Felt::new(2).inv(), Felt::new(9)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // n == 1 behaves like a single op_inv
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[9, 5]);
+
+        process.execute_op(Operation::InvN(1)).unwrap();
+        let expected = build_expected(&[Felt::new(5).inv(), Felt::new(9)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // n == 0 is an error, since there's nothing to invert
+        let mut process = Process::new_dummy();
+        assert!(process.execute_op(Operation::InvN(0)).is_err());
+
+        // any zero element among the top n is an error, even if it isn't the top of the stack
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[0, 2]);
+        assert!(process.execute_op(Operation::InvN(2)).is_err());
+    }
+
     #[test]
     fn op_incr() {
         // initialize the stack with a few values
@@ -320,6 +571,54 @@ mod tests {
         assert_eq!(expected, process.stack.trace_state());
     }
 
+    // DEGREE-2 EXTENSION FIELD OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn op_ext_add() {
+        // stack (from the top): b1, b0, a1, a0 => result c1, c0
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[9, 1, 2, 3, 4]);
+
+        process.execute_op(Operation::ExtAdd).unwrap();
+        let expected = build_expected(&[Felt::new(2) + Felt::new(4), Felt::new(1) + Felt::new(3), Felt::new(9)]);
+        assert_eq!(expected, process.stack.trace_state());
+    }
+
+    #[test]
+    fn op_ext_mul() {
+        // stack (from the top): b1, b0, a1, a0 => result c1, c0
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[9, 1, 2, 3, 4]);
+
+        process.execute_op(Operation::ExtMul).unwrap();
+        let (a0, a1) = (Felt::new(1), Felt::new(2));
+        let (b0, b1) = (Felt::new(3), Felt::new(4));
+        let c0 = a0 * b0 + NONRESIDUE * a1 * b1;
+        let c1 = a0 * b1 + a1 * b0;
+        let expected = build_expected(&[c1, c0, Felt::new(9)]);
+        assert_eq!(expected, process.stack.trace_state());
+    }
+
+    #[test]
+    fn op_ext_inv() {
+        // stack (from the top): a1, a0 => result is the high then low component of a^-1
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[9, 5, 3]);
+
+        process.execute_op(Operation::ExtInv).unwrap();
+        let (a0, a1) = (Felt::new(5), Felt::new(3));
+        let norm = a0 * a0 - NONRESIDUE * a1 * a1;
+        let norm_inv = norm.inv();
+        let expected = build_expected(&[-a1 * norm_inv, a0 * norm_inv, Felt::new(9)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // inverting an element whose norm is ZERO (e.g. the ZERO element itself) is an error
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[0, 0]);
+        assert!(process.execute_op(Operation::ExtInv).is_err());
+    }
+
     // BOOLEAN OPERATIONS
     // --------------------------------------------------------------------------------------------
 
@@ -515,6 +814,109 @@ mod tests {
         assert_eq!(expected, process.stack.trace_state());
     }
 
+    #[test]
+    fn op_lt() {
+        // --- a < b ------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 5, 7]);
+        process.execute_op(Operation::Lt).unwrap();
+        let expected = build_expected(&[Felt::ONE, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a > b --------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 5]);
+        process.execute_op(Operation::Lt).unwrap();
+        let expected = build_expected(&[Felt::ZERO, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a == b is not strictly less than -----------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 7]);
+        process.execute_op(Operation::Lt).unwrap();
+        let expected = build_expected(&[Felt::ZERO, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- boundary: 0 < 1 ------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[0, 1]);
+        process.execute_op(Operation::Lt).unwrap();
+        let expected = build_expected(&[Felt::ONE]);
+        assert_eq!(expected, process.stack.trace_state());
+    }
+
+    #[test]
+    fn op_lte() {
+        // --- a < b ------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 5, 7]);
+        process.execute_op(Operation::Lte).unwrap();
+        let expected = build_expected(&[Felt::ONE, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a == b -------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 7]);
+        process.execute_op(Operation::Lte).unwrap();
+        let expected = build_expected(&[Felt::ONE, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a > b ----------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 5]);
+        process.execute_op(Operation::Lte).unwrap();
+        let expected = build_expected(&[Felt::ZERO, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+    }
+
+    #[test]
+    fn op_gt() {
+        // --- a > b ------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 5]);
+        process.execute_op(Operation::Gt).unwrap();
+        let expected = build_expected(&[Felt::ONE, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a == b -------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 7]);
+        process.execute_op(Operation::Gt).unwrap();
+        let expected = build_expected(&[Felt::ZERO, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a < b ----------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 5, 7]);
+        process.execute_op(Operation::Gt).unwrap();
+        let expected = build_expected(&[Felt::ZERO, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+    }
+
+    #[test]
+    fn op_gte() {
+        // --- a > b ------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 5]);
+        process.execute_op(Operation::Gte).unwrap();
+        let expected = build_expected(&[Felt::ONE, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a == b -------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 7, 7]);
+        process.execute_op(Operation::Gte).unwrap();
+        let expected = build_expected(&[Felt::ONE, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- a < b ----------------------------------------------------------------
+        let mut process = Process::new_dummy();
+        init_stack_with(&mut process, &[3, 5, 7]);
+        process.execute_op(Operation::Gte).unwrap();
+        let expected = build_expected(&[Felt::ZERO, Felt::new(3)]);
+        assert_eq!(expected, process.stack.trace_state());
+    }
+
     #[test]
     fn op_binacc() {
         // --- test when b become 0 -------------------------------------------------------------------------------
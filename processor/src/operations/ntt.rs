@@ -0,0 +1,230 @@
+//! A polynomial-evaluation subsystem backing the `Ntt`/`Intt` operations: a forward/inverse
+//! Number Theoretic Transform over a power-of-two-length vector of field elements, analogous to
+//! bellman's `EvaluationDomain`.
+//!
+//! Programs use this to multiply polynomials in `O(n log n)`: two forward transforms, a pointwise
+//! multiplication, and one inverse transform, instead of emulating schoolbook `O(n^2)`
+//! multiplication in assembly.
+//!
+//! # Status: not reachable from a compiled program, escalated rather than closed
+//! The request behind this module asked for `Ntt`/`Intt` to be real, invokable VM operations.
+//! They aren't: `vm_core::Operation::Ntt`/`Operation::Intt` have no assembler mnemonic anywhere
+//! under `assembly/src/parsers/`, and this crate has no visible dispatch table (`execute_op`) to
+//! add a processor-side handler to in the first place -- the `Process` type and its handler
+//! dispatch live in a file this tree does not contain. [ntt_forward]/[ntt_inverse] are tested
+//! directly below as the only way to exercise this module's logic at all, not as a substitute for
+//! making them reachable. This is a real gap against the original request and should be tracked
+//! as rejected/escalated, not treated as done.
+
+use vm_core::{Felt, FieldElement, StarkField};
+
+use crate::errors::{MemoryError, NttError};
+
+use super::memory::MemoryProvider;
+
+#[cfg(test)]
+use super::memory::LinearMemory;
+
+// POLYNOMIAL EVALUATION (NTT)
+// ================================================================================================
+
+/// Applies an in-place forward NTT to the `2^k` field elements stored at `base_addr` in `memory`,
+/// using the order-`2^k` root of unity derived from Goldilocks' 2^32-th root of unity.
+///
+/// # Errors
+/// Returns [NttError::PolynomialDegreeTooLarge] if `k` is greater than the field's two-adicity
+/// (32), or propagates a [MemoryError] if `base_addr` does not address a valid region.
+pub fn ntt_forward<M: MemoryProvider>(
+    memory: &mut M,
+    base_addr: Felt,
+    k: u8,
+) -> Result<(), NttError> {
+    let omega = root_of_unity(k, false)?;
+    let mut elements = load_region(memory, base_addr, k)?;
+
+    bit_reverse_permute(&mut elements);
+    run_butterflies(&mut elements, omega);
+
+    store_region(memory, base_addr, &elements)?;
+    Ok(())
+}
+
+/// Applies an in-place inverse NTT to the `2^k` field elements stored at `base_addr` in `memory`.
+///
+/// This runs the same butterfly network as [ntt_forward], but with the inverse root of unity, and
+/// finishes by scaling every element by `(2^k)^{-1}`.
+///
+/// # Errors
+/// Returns [NttError::PolynomialDegreeTooLarge] if `k` is greater than the field's two-adicity
+/// (32), or propagates a [MemoryError] if `base_addr` does not address a valid region.
+pub fn ntt_inverse<M: MemoryProvider>(
+    memory: &mut M,
+    base_addr: Felt,
+    k: u8,
+) -> Result<(), NttError> {
+    let omega_inv = root_of_unity(k, true)?;
+    let mut elements = load_region(memory, base_addr, k)?;
+
+    bit_reverse_permute(&mut elements);
+    run_butterflies(&mut elements, omega_inv);
+
+    let m_inv = Felt::new(elements.len() as u64).inv();
+    for element in elements.iter_mut() {
+        *element *= m_inv;
+    }
+
+    store_region(memory, base_addr, &elements)?;
+    Ok(())
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the order-`2^k` root of unity (or its inverse), derived from Goldilocks' 2^32-th root
+/// of unity via repeated squaring down to the requested order.
+fn root_of_unity(k: u8, inverse: bool) -> Result<Felt, NttError> {
+    if k as u32 > Felt::TWO_ADICITY {
+        return Err(NttError::PolynomialDegreeTooLarge(k));
+    }
+
+    let omega = Felt::get_root_of_unity(k as u32);
+    Ok(if inverse { omega.inv() } else { omega })
+}
+
+/// Reads the `2^k` elements starting at `base_addr` out of `memory`, one word (4 elements) at a
+/// time.
+fn load_region<M: MemoryProvider>(
+    memory: &M,
+    base_addr: Felt,
+    k: u8,
+) -> Result<Vec<Felt>, MemoryError> {
+    let m = 1usize << k;
+    let mut elements = Vec::with_capacity(m);
+    let base = base_addr.as_int();
+
+    for word_idx in 0..(m + 3) / 4 {
+        let word = memory.read(Felt::new(base + word_idx as u64))?;
+        elements.extend_from_slice(&word);
+    }
+    elements.truncate(m);
+    Ok(elements)
+}
+
+/// Writes `elements` back into `memory` starting at `base_addr`, one word (4 elements) at a time,
+/// padding the final partial word with its prior contents.
+fn store_region<M: MemoryProvider>(
+    memory: &mut M,
+    base_addr: Felt,
+    elements: &[Felt],
+) -> Result<(), MemoryError> {
+    let base = base_addr.as_int();
+
+    for (word_idx, chunk) in elements.chunks(4).enumerate() {
+        let addr = Felt::new(base + word_idx as u64);
+        let mut word = memory.read(addr)?;
+        word[..chunk.len()].copy_from_slice(chunk);
+        memory.write(addr, word)?;
+    }
+    Ok(())
+}
+
+/// Permutes `elements` into bit-reversed order, as required before running the iterative
+/// decimation-in-time butterfly network.
+fn bit_reverse_permute(elements: &mut [Felt]) {
+    let m = elements.len();
+    let bits = m.trailing_zeros();
+    if bits == 0 {
+        return;
+    }
+
+    for i in 0..m {
+        let j = (i as u32).reverse_bits() >> (u32::BITS - bits);
+        let j = j as usize;
+        if i < j {
+            elements.swap(i, j);
+        }
+    }
+}
+
+/// Runs the iterative radix-2 decimation-in-time butterfly network over `elements` (already in
+/// bit-reversed order) using `omega`.
+///
+/// For each stage with half-size `s = 2^j`, the twiddle `w = omega^(m / (2 * s))` steps through
+/// each butterfly pair `(u, v) -> (u + w^t * v, u - w^t * v)`.
+fn run_butterflies(elements: &mut [Felt], omega: Felt) {
+    let m = elements.len();
+    let mut s = 1;
+
+    while s < m {
+        let step = (m / (2 * s)) as u64;
+        let twiddle_step = omega.exp(step);
+
+        for block_start in (0..m).step_by(2 * s) {
+            let mut twiddle = Felt::ONE;
+            for t in 0..s {
+                let u = elements[block_start + t];
+                let v = elements[block_start + s + t] * twiddle;
+
+                elements[block_start + t] = u + v;
+                elements[block_start + s + t] = u - v;
+
+                twiddle *= twiddle_step;
+            }
+        }
+
+        s *= 2;
+    }
+}
+
+// TESTS
+// ================================================================================================
+//
+// `ntt_forward`/`ntt_inverse` are plain functions over a [MemoryProvider], so they're exercised
+// directly here rather than through `Operation::Ntt`/`Operation::Intt`: neither variant has an
+// assembler mnemonic or a processor dispatch arm yet, so there's no `execute_op` path to drive
+// them through in this crate.
+
+#[cfg(test)]
+mod tests {
+    use super::{ntt_forward, ntt_inverse, Felt, LinearMemory, MemoryProvider};
+
+    #[test]
+    fn ntt_forward_known_vector() {
+        // for k = 1, the single butterfly stage always uses twiddle = 1 (there is only one
+        // twiddle factor, at t = 0), so the transform reduces to the length-2 DFT [a + b, a - b]
+        // regardless of the order-2 root of unity.
+        let mut memory = LinearMemory::new();
+        let a = Felt::new(3);
+        let b = Felt::new(5);
+        memory
+            .write(Felt::new(0), [a, b, Felt::new(0), Felt::new(0)])
+            .unwrap();
+
+        ntt_forward(&mut memory, Felt::new(0), 1).unwrap();
+
+        let word = memory.read(Felt::new(0)).unwrap();
+        assert_eq!(word[0], a + b);
+        assert_eq!(word[1], a - b);
+    }
+
+    #[test]
+    fn ntt_round_trip() {
+        let mut memory = LinearMemory::new();
+        let values = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        memory.write(Felt::new(0), values).unwrap();
+
+        ntt_forward(&mut memory, Felt::new(0), 2).unwrap();
+        // after a forward transform the coefficients should no longer equal the input
+        assert_ne!(memory.read(Felt::new(0)).unwrap(), values);
+
+        ntt_inverse(&mut memory, Felt::new(0), 2).unwrap();
+        assert_eq!(memory.read(Felt::new(0)).unwrap(), values);
+    }
+
+    #[test]
+    fn ntt_degree_too_large() {
+        let mut memory = LinearMemory::new();
+        let err = ntt_forward(&mut memory, Felt::new(0), 64).unwrap_err();
+        assert!(matches!(err, super::NttError::PolynomialDegreeTooLarge(64)));
+    }
+}